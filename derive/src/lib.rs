@@ -0,0 +1,134 @@
+//! Provides `#[derive(TakeSlice)]` for the `owned_slice` crate.
+//!
+//! The derive forwards `Index`, `IndexMut`, `TakeSlice` and `UniqueIndexMut`
+//! to one field of the struct, which is picked with `#[take_slice(field = "name")]`.
+//! It may be left out if the struct has a single field.
+//!
+//! - `len = "expr"` overrides the length, which otherwise comes from
+//!   the field's own `TakeSlice::len`.
+//! - `index = "Type"` sets the index type, which defaults to `usize`.
+
+extern crate proc_macro;
+extern crate proc_macro2;
+extern crate syn;
+#[macro_use]
+extern crate quote;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{Data, DeriveInput, Expr, Fields, Index, LitStr, Member, Type};
+
+#[proc_macro_derive(TakeSlice, attributes(take_slice))]
+pub fn derive_take_slice(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+struct Options {
+    field: Option<String>,
+    len: Option<Expr>,
+    index: Option<Type>,
+}
+
+fn parse_options(input: &DeriveInput) -> syn::Result<Options> {
+    let mut options = Options { field: None, len: None, index: None };
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("take_slice")) {
+        attr.parse_nested_meta(|meta| {
+            let value: LitStr = meta.value()?.parse()?;
+            if meta.path.is_ident("field") {
+                options.field = Some(value.value());
+            } else if meta.path.is_ident("len") {
+                options.len = Some(value.parse()?);
+            } else if meta.path.is_ident("index") {
+                options.index = Some(value.parse()?);
+            } else {
+                return Err(meta.error("expected `field`, `len` or `index`"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(options)
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let options = parse_options(input)?;
+    let fields = match input.data {
+        Data::Struct(ref data) => &data.fields,
+        _ => return Err(syn::Error::new_spanned(input, "TakeSlice can only be derived for structs")),
+    };
+    let members: Vec<(Member, &Type)> = match *fields {
+        Fields::Named(ref named) => named.named.iter()
+            .map(|f| (Member::Named(f.ident.clone().unwrap()), &f.ty))
+            .collect(),
+        Fields::Unnamed(ref unnamed) => unnamed.unnamed.iter().enumerate()
+            .map(|(i, f)| (Member::Unnamed(Index::from(i)), &f.ty))
+            .collect(),
+        Fields::Unit => Vec::new(),
+    };
+    let (member, ty) = match options.field {
+        Some(ref name) => members.into_iter()
+            .find(|(m, _)| match *m {
+                Member::Named(ref ident) => ident == name,
+                Member::Unnamed(ref index) => index.index.to_string() == *name,
+            })
+            .ok_or_else(|| syn::Error::new_spanned(input, format!("no field named `{}`", name)))?,
+        None if members.len() == 1 => members.into_iter().next().unwrap(),
+        None => {
+            return Err(syn::Error::new_spanned(input,
+                "specify the backing field with #[take_slice(field = \"...\")]"))
+        }
+    };
+    let index = match options.index {
+        Some(index) => quote!(#index),
+        None => quote!(usize),
+    };
+    let len = match options.len {
+        Some(len) => quote!(#len),
+        None => quote!(::owned_slice::TakeSlice::len(&self.#member)),
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut bounds: Vec<TokenStream2> = where_clause
+        .map(|w| w.predicates.iter().map(|p| quote!(#p)).collect())
+        .unwrap_or_default();
+    bounds.push(quote!(#ty: ::owned_slice::__private::Index<#index>));
+    let output = quote!(<#ty as ::owned_slice::__private::Index<#index>>::Output);
+
+    Ok(quote! {
+        impl #impl_generics ::owned_slice::__private::Index<#index> for #name #ty_generics
+            where #(#bounds,)*
+        {
+            type Output = #output;
+            fn index(&self, index: #index) -> &Self::Output {
+                &self.#member[index]
+            }
+        }
+
+        impl #impl_generics ::owned_slice::__private::IndexMut<#index> for #name #ty_generics
+            where #(#bounds,)* #ty: ::owned_slice::__private::IndexMut<#index>
+        {
+            fn index_mut(&mut self, index: #index) -> &mut Self::Output {
+                &mut self.#member[index]
+            }
+        }
+
+        impl #impl_generics ::owned_slice::TakeSlice<#output, #index> for #name #ty_generics
+            where #(#bounds,)* #output: Sized
+        {
+            fn len(&self) -> #index {
+                #len
+            }
+        }
+
+        // indexing goes straight through to the field. The bound is higher-ranked
+        // so that it may be false, e.g. for a `Vec` field, without failing to compile.
+        unsafe impl #impl_generics ::owned_slice::UniqueIndexMut<#index> for #name #ty_generics
+            where #(#bounds,)* for<'__unique> #ty: ::owned_slice::UniqueIndexMut<#index>
+        {
+        }
+    })
+}
@@ -0,0 +1,32 @@
+// `arrayvec::ArrayVec` dereferences to `[T]` without implementing `Index`
+// itself, so it is sliced through the `[T]` impl (`av[..].index_range(..)`),
+// or through an `IndexedSlice` to split or iterate it mutably.
+
+use arrayvec::ArrayVec;
+use super::IndexedSlice;
+
+impl<'s, T, const CAP: usize> From<&'s mut ArrayVec<T, CAP>> for IndexedSlice<'s, T> {
+    fn from(av: &'s mut ArrayVec<T, CAP>) -> Self {
+        IndexedSlice::new(av)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrayvec::ArrayVec;
+    use {TakeSlice, IndexedSlice};
+
+    #[test]
+    fn take_slice() {
+        let mut av: ArrayVec<u32, 8> = (0..5).collect();
+        assert_eq!(av[..].index_range(1..4), [1, 2, 3]);
+        {
+            let mut indexed = IndexedSlice::from(&mut av);
+            let (mut a, mut b) = indexed.index_range_full_mut(..).split_at_mut(2);
+            a.reverse();
+            b.reverse();
+        }
+        assert_eq!(&av[..], &[1, 0, 4, 3, 2]);
+        assert!(av[..].try_index_range(0..8).is_err());
+    }
+}
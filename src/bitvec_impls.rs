@@ -0,0 +1,46 @@
+use bitvec::order::BitOrder;
+use bitvec::slice::BitSlice;
+use bitvec::store::BitStore;
+use bitvec::vec::BitVec;
+use super::TakeSlice;
+
+// Bits can't be borrowed mutably, so these containers only support
+// read-only slicing.
+impl<T: BitStore, O: BitOrder> TakeSlice<bool, usize> for BitVec<T, O> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T: BitStore, O: BitOrder> TakeSlice<bool, usize> for BitSlice<T, O> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitvec::prelude::*;
+    use TakeSlice;
+
+    #[test]
+    fn large_windows() {
+        // every third bit set, across a few million bits
+        let bits: BitVec = (0..3_000_000).map(|i| i % 3 == 0).collect();
+        let window = bits.index_range(1_000_000..2_500_000);
+        assert_eq!(window.len(), 1_500_000);
+        assert_eq!(window.iter().filter(|b| **b).count(), 500_000);
+        assert!(!window[0]);
+        assert!(window[2]);
+        assert_eq!(window.position(|b| *b), Some(2));
+        assert_eq!(window.chunks(64).count(), 23_438);
+        assert!(bits.try_index_range(2_999_999..3_000_001).is_err());
+    }
+
+    #[test]
+    fn bit_slice() {
+        let bits = bits![0, 1, 1, 0, 1];
+        assert_eq!(bits.index_range(1..4), [true, true, false]);
+        assert_eq!(bits[2..].index_range(0..3).iter().filter(|b| **b).count(), 2);
+    }
+}
@@ -0,0 +1,163 @@
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::ops::{Index, IndexMut};
+use core::slice;
+use bytes::{Buf, BufMut};
+use bytes::buf::UninitSlice;
+use super::{Slice, SliceMut, BoundsPolicy};
+use util::{deque_segment, deque_segment_mut};
+
+/// A byte container which may be able to hand out contiguous runs of bytes,
+/// used by the `Buf` implementation for `Slice`.
+///
+/// The default implementation returns one byte at a time, so custom
+/// containers can opt in with an empty `impl`.
+pub trait ByteChunks: Index<usize, Output = u8> {
+    /// Returns a contiguous run of the bytes in `start..start + len`,
+    /// beginning at `start`. Must not be empty if `len > 0`.
+    fn chunk_at(&self, start: usize, len: usize) -> &[u8] {
+        if len == 0 { &[] } else { slice::from_ref(&self[start]) }
+    }
+}
+
+/// The mutable version of `ByteChunks`, used by the `BufMut`
+/// implementation for `SliceMut`.
+pub trait ByteChunksMut: ByteChunks + IndexMut<usize, Output = u8> {
+    /// Returns a contiguous run of the bytes in `start..start + len`,
+    /// beginning at `start`. Must not be empty if `len > 0`.
+    fn chunk_at_mut(&mut self, start: usize, len: usize) -> &mut [u8] {
+        if len == 0 { &mut [] } else { slice::from_mut(&mut self[start]) }
+    }
+}
+
+impl ByteChunks for Vec<u8> {
+    fn chunk_at(&self, start: usize, len: usize) -> &[u8] {
+        &self[start..start + len]
+    }
+}
+
+impl ByteChunksMut for Vec<u8> {
+    fn chunk_at_mut(&mut self, start: usize, len: usize) -> &mut [u8] {
+        &mut self[start..start + len]
+    }
+}
+
+impl ByteChunks for VecDeque<u8> {
+    fn chunk_at(&self, start: usize, len: usize) -> &[u8] {
+        deque_segment(self, start, len)
+    }
+}
+
+impl ByteChunksMut for VecDeque<u8> {
+    fn chunk_at_mut(&mut self, start: usize, len: usize) -> &mut [u8] {
+        deque_segment_mut(self, start, len)
+    }
+}
+
+impl<'a, K, P> Buf for Slice<'a, K, usize, u8, P>
+    where K: ?Sized + ByteChunks,
+          P: BoundsPolicy
+{
+    fn remaining(&self) -> usize {
+        self.len
+    }
+
+    fn chunk(&self) -> &[u8] {
+        self.list.chunk_at(self.start, self.len)
+    }
+
+    fn advance(&mut self, cnt: usize) {
+        assert!(cnt <= self.len, "cannot advance past the end of the slice: {} > {}", cnt, self.len);
+        self.start += cnt;
+        self.len -= cnt;
+    }
+}
+
+unsafe impl<'a, K, P> BufMut for SliceMut<'a, K, usize, u8, P>
+    where K: ?Sized + ByteChunksMut,
+          P: BoundsPolicy
+{
+    fn remaining_mut(&self) -> usize {
+        self.len
+    }
+
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        assert!(cnt <= self.len, "cannot advance past the end of the slice: {} > {}", cnt, self.len);
+        self.start += cnt;
+        self.len -= cnt;
+    }
+
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        let (start, len) = (self.start, self.len);
+        UninitSlice::new(self.list_mut().chunk_at_mut(start, len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::ops::{Index, IndexMut};
+    use bytes::{Buf, BufMut};
+    use super::{ByteChunks, ByteChunksMut};
+    use TakeSlice;
+
+    // a ring buffer whose contents wrap around the end of its storage
+    fn test_vec() -> VecDeque<u8> {
+        let mut v: VecDeque<u8> = VecDeque::with_capacity(16);
+        v.extend(b"xxxxxxxxxx");
+        v.drain(..9);
+        v.extend(b"hello\nworld");
+        v.pop_front();
+        v
+    }
+
+    // a container without contiguous storage
+    struct Bytes(Vec<u8>);
+
+    impl Index<usize> for Bytes {
+        type Output = u8;
+        fn index(&self, index: usize) -> &u8 {
+            &self.0[index]
+        }
+    }
+
+    impl IndexMut<usize> for Bytes {
+        fn index_mut(&mut self, index: usize) -> &mut u8 {
+            &mut self.0[index]
+        }
+    }
+
+    impl ByteChunks for Bytes {}
+    impl ByteChunksMut for Bytes {}
+
+    #[test]
+    fn buf() {
+        let v = test_vec();
+        let mut slice = v.index_range(1..11);
+        assert!(slice.chunk().len() < 10);
+        assert_eq!(slice.get_u8(), b'e');
+        assert_eq!(slice.copy_to_bytes(9), &b"llo\nworld"[..]);
+        assert_eq!(slice.remaining(), 0);
+
+        let b = Bytes(vec![1, 2, 3]);
+        let mut slice = ::Slice::new(&b, 1..3);
+        assert_eq!(slice.chunk(), &[2]);
+        assert_eq!(slice.get_u16(), 0x0203);
+    }
+
+    #[test]
+    fn buf_mut() {
+        let mut v = test_vec();
+        {
+            let mut slice = v.index_range_mut(2..10);
+            slice.put_slice(b"LLO");
+            slice.put_u16(0x0a57);
+            assert_eq!(slice.remaining_mut(), 3);
+        }
+        assert_eq!(v.iter().cloned().collect::<Vec<_>>(), b"heLLO\nWorld");
+
+        let mut b = Bytes(vec![0; 4]);
+        ::SliceMut::new(&mut b, 0..4).put_u32(0xdeadbeef);
+        assert_eq!(b.0, vec![0xde, 0xad, 0xbe, 0xef]);
+    }
+}
@@ -0,0 +1,235 @@
+use core::cell::{BorrowError, BorrowMutError, Cell, RefCell};
+use core::marker;
+use core::mem;
+use core::ops::{Index, IndexMut, Range};
+use num_traits::Zero;
+use util::assert_in_bounds;
+use {Idx, Slice, SliceMut, TakeSlice};
+
+/// A window into a container behind a `RefCell`.
+///
+/// `CellSlice` is `Copy`, so any number of windows into one shared container
+/// can be handed out. Each access borrows the `RefCell` for just as long as
+/// it needs to, so the usual `RefCell` rules are checked at runtime.
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::collections::VecDeque;
+/// use owned_slice::CellSlice;
+///
+/// let deque: RefCell<VecDeque<u32>> = RefCell::new((0..6).collect());
+/// let left = CellSlice::new(&deque, 0..3);
+/// let right = CellSlice::new(&deque, 3..6);
+///
+/// left.with_mut(|mut s| s.reverse());
+/// right.set(0, 9);
+/// assert_eq!(left.with(|s| s.to_vec()), [2, 1, 0]);
+/// assert_eq!(*deque.borrow(), [2, 1, 0, 9, 4, 5]);
+/// ```
+///
+/// Containers of `Cell`s need no wrapper at all, since a plain `Slice` of
+/// them is already `Copy` and can be written through; see `Slice::set`.
+pub struct CellSlice<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a> {
+    cell: &'a RefCell<K>,
+    start: I,
+    len: I,
+    ty: marker::PhantomData<T>,
+}
+
+impl<'a, K, I, T> Clone for CellSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, K, I, T> Copy for CellSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+}
+
+impl<'a, K, I, T> CellSlice<'a, K, I, T>
+    where K: ?Sized + TakeSlice<T, I>,
+          I: Idx
+{
+    /// Creates a window of the container in `cell` covering `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds,
+    /// or if the container is currently mutably borrowed.
+    pub fn new(cell: &'a RefCell<K>, index: Range<I>) -> CellSlice<'a, K, I, T> {
+        assert_in_bounds(&index, cell.borrow().len());
+        CellSlice {
+            cell,
+            start: index.start,
+            len: index.end - index.start,
+            ty: marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, K, I, T> CellSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    /// Returns the number of elements in the slice.
+    #[inline]
+    pub fn len(&self) -> I {
+        self.len
+    }
+
+    /// Returns `true` if the slice has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == Zero::zero()
+    }
+
+    /// Narrows the window to `range`, which is relative to this slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of the bounds of this slice.
+    pub fn subslice(&self, range: Range<I>) -> CellSlice<'a, K, I, T> {
+        assert_in_bounds(&range, self.len);
+        CellSlice {
+            cell: self.cell,
+            start: self.start + range.start,
+            len: range.end - range.start,
+            ty: marker::PhantomData,
+        }
+    }
+
+    /// Divides the window into two at an index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn split_at(self, mid: I) -> (CellSlice<'a, K, I, T>, CellSlice<'a, K, I, T>) {
+        assert_in_bounds(&(Zero::zero()..mid), self.len);
+        (self.subslice(Zero::zero()..mid), self.subslice(mid..self.len))
+    }
+
+    /// Calls `f` with an immutable view of the window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the container is currently mutably borrowed.
+    pub fn with<R, F>(&self, f: F) -> R
+        where F: FnOnce(Slice<K, I, T>) -> R
+    {
+        let list = self.cell.borrow();
+        f(Slice::new(&*list, self.start..self.start + self.len))
+    }
+
+    /// Calls `f` with an immutable view of the window, or returns an error
+    /// if the container is currently mutably borrowed.
+    pub fn try_with<R, F>(&self, f: F) -> Result<R, BorrowError>
+        where F: FnOnce(Slice<K, I, T>) -> R
+    {
+        let list = self.cell.try_borrow()?;
+        Ok(f(Slice::new(&*list, self.start..self.start + self.len)))
+    }
+
+    /// Returns a clone of the element at `index`,
+    /// or `None` if it is out of bounds.
+    pub fn get(&self, index: I) -> Option<T>
+        where T: Clone
+    {
+        self.with(|slice| slice.get(index).cloned())
+    }
+}
+
+impl<'a, K, I, T> CellSlice<'a, K, I, T>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx
+{
+    /// Calls `f` with a mutable view of the window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the container is currently borrowed.
+    pub fn with_mut<R, F>(&self, f: F) -> R
+        where F: FnOnce(SliceMut<K, I, T>) -> R
+    {
+        let mut list = self.cell.borrow_mut();
+        f(SliceMut::new(&mut *list, self.start..self.start + self.len))
+    }
+
+    /// Calls `f` with a mutable view of the window, or returns an error
+    /// if the container is currently borrowed.
+    pub fn try_with_mut<R, F>(&self, f: F) -> Result<R, BorrowMutError>
+        where F: FnOnce(SliceMut<K, I, T>) -> R
+    {
+        let mut list = self.cell.try_borrow_mut()?;
+        Ok(f(SliceMut::new(&mut *list, self.start..self.start + self.len)))
+    }
+
+    /// Replaces the element at `index`, returning the old one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds, or if the container is currently borrowed.
+    pub fn replace(&self, index: I, value: T) -> T {
+        self.with_mut(|mut slice| mem::replace(&mut slice[index], value))
+    }
+
+    /// Sets the element at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds, or if the container is currently borrowed.
+    pub fn set(&self, index: I, value: T) {
+        self.replace(index, value);
+    }
+}
+
+impl<'a, K, I, T> Slice<'a, K, I, Cell<T>>
+    where K: ?Sized + Index<I, Output = Cell<T>>,
+          I: Idx
+{
+    /// Sets the element at `index` through its `Cell`.
+    pub fn set(&self, index: I, value: T) {
+        self[index].set(value);
+    }
+
+    /// Replaces the element at `index` through its `Cell`, returning the old one.
+    pub fn replace(&self, index: I, value: T) -> T {
+        self[index].replace(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+    use std::collections::VecDeque;
+    use {CellSlice, TakeSlice};
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..10).collect()
+    }
+
+    #[test]
+    fn shared_windows() {
+        let v = RefCell::new(test_vec());
+        let (a, b) = CellSlice::new(&v, 2..8).split_at(3);
+        assert_eq!(a.get(0), Some(2));
+        assert_eq!(b.replace(2, 0), 7);
+        a.with_mut(|_| assert!(b.try_with(|_| ()).is_err()));
+        let _guard = v.borrow();
+        assert!(a.try_with_mut(|mut a| a.fill(0)).is_err());
+        assert_eq!(a.try_with(|a| a.to_vec()).unwrap(), [2, 3, 4]);
+    }
+
+    #[test]
+    fn cell_containers() {
+        let v: Vec<Cell<usize>> = (0..5).map(Cell::new).collect();
+        let (a, b) = (v.index_range(0..3), v.index_range(2..5));
+        a.set(2, 9);
+        assert_eq!(b.replace(0, 7), 9);
+        assert_eq!(a[2].get(), 7);
+    }
+}
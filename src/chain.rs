@@ -0,0 +1,177 @@
+use core::fmt::{self, Debug};
+use core::ops::Index;
+use num_traits::Zero;
+use {BoundsPolicy, Idx, Iter, Slice, TakeSlice};
+
+/// An immutable view of two slices one after the other,
+/// created with `Slice::chain`.
+///
+/// The two slices may borrow different kinds of container,
+/// as long as they hold the same element type.
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use owned_slice::TakeSlice;
+///
+/// let history = vec![1, 2, 3];
+/// let mut recent = VecDeque::new();
+/// recent.push_back(4);
+/// recent.push_back(5);
+/// let both = history.index_range_from(1..).chain(recent.index_range_full(..));
+/// assert_eq!(both.len(), 4);
+/// assert_eq!(both[2], 4);
+/// assert_eq!(format!("{:?}", both), "[2, 3, 4, 5]");
+/// ```
+pub struct Chain<'a, A, B, I, T>
+    where A: 'a + ?Sized + Index<I, Output = T>,
+          B: 'a + ?Sized + Index<I, Output = T>,
+          I: 'a + Idx,
+          T: 'a
+{
+    first: Slice<'a, A, I, T>,
+    second: Slice<'a, B, I, T>,
+}
+
+impl<'a, K, I, T, P> Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Returns a view which behaves like `self` followed by `other`.
+    pub fn chain<L>(self, other: Slice<'a, L, I, T>) -> Chain<'a, K, L, I, T>
+        where L: ?Sized + Index<I, Output = T>
+    {
+        Chain {
+            first: self.with_policy(),
+            second: other,
+        }
+    }
+}
+
+impl<'a, A, B, I, T> Chain<'a, A, B, I, T>
+    where A: ?Sized + Index<I, Output = T>,
+          B: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    /// Returns the combined number of elements in both slices.
+    #[inline]
+    pub fn len(&self) -> I {
+        self.first.len() + self.second.len()
+    }
+
+    /// Returns `true` if both slices are empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == Zero::zero()
+    }
+
+    /// Returns a reference to the element at `index`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: I) -> Option<&'a T> {
+        let split = self.first.len();
+        if index < split {
+            self.first.get(index)
+        } else {
+            self.second.get(index - split)
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, Self, I, T> {
+        self.index_range_full(..).iter()
+    }
+
+    /// Splits the view back into its two slices.
+    pub fn into_parts(self) -> (Slice<'a, A, I, T>, Slice<'a, B, I, T>) {
+        (self.first, self.second)
+    }
+}
+
+impl<'a, A, B, I, T> Clone for Chain<'a, A, B, I, T>
+    where A: ?Sized + Index<I, Output = T>,
+          B: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, A, B, I, T> Copy for Chain<'a, A, B, I, T>
+    where A: ?Sized + Index<I, Output = T>,
+          B: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+}
+
+impl<'a, A, B, I, T> Index<I> for Chain<'a, A, B, I, T>
+    where A: ?Sized + Index<I, Output = T>,
+          B: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: I) -> &T {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, self.len()),
+        }
+    }
+}
+
+impl<'a, A, B, I, T> TakeSlice<T, I> for Chain<'a, A, B, I, T>
+    where A: ?Sized + Index<I, Output = T>,
+          B: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    fn len(&self) -> I {
+        self.first.len() + self.second.len()
+    }
+}
+
+impl<'a, A, B, I, T> Debug for Chain<'a, A, B, I, T>
+    where A: ?Sized + Index<I, Output = T>,
+          B: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          T: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use TakeSlice;
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..10).collect()
+    }
+
+    #[test]
+    fn chain() {
+        let v = test_vec();
+        // the two halves of a region which wraps past the end
+        let c = v.index_range_from(8..).chain(v.index_range_to(..3));
+        assert_eq!(format!("{:?}", c), "[8, 9, 0, 1, 2]");
+        assert_eq!(c[1], 9);
+        assert_eq!(c[2], 0);
+        assert_eq!(c.get(5), None);
+        assert_eq!(c.index_range(1..4), [9, 0, 1]);
+        assert_eq!(c.iter().next_back(), Some(&2));
+        let (a, b) = c.into_parts();
+        assert_eq!((a.len(), b.len()), (2, 3));
+    }
+
+    #[test]
+    fn chain_different_containers() {
+        let v = test_vec();
+        let arr = [10, 11];
+        let c = arr.index_range_full(..).chain(v.index_range_to(..0));
+        assert_eq!(c.len(), 2);
+        assert!(!c.is_empty());
+        assert_eq!(c.iter().sum::<usize>(), 21);
+    }
+}
@@ -0,0 +1,769 @@
+use core::ops::{Index, IndexMut};
+use core::marker;
+use num_traits::{Zero, One};
+use super::{Idx, Slice, SliceMut, UniqueIndexMut, BoundsPolicy, Checked};
+use util::{to_usize, from_usize, assert_len_fits};
+
+impl<'a, K, I, T, P> Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Returns an iterator over all contiguous windows of length `size`.
+    /// The windows overlap. If the slice is shorter than `size`,
+    /// the iterator returns no values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn windows(self, size: I) -> Windows<'a, K, I, T, P> {
+        assert!(size != Zero::zero(), "window size must be non-zero");
+        assert_len_fits(self.len);
+        Windows {
+            slice: self,
+            size,
+        }
+    }
+
+    /// Returns an iterator over `size` elements of the slice at a time,
+    /// starting at the beginning of the slice. The chunks don't overlap,
+    /// and the last chunk will be shorter if `size` does not divide
+    /// the length of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn chunks(self, size: I) -> Chunks<'a, K, I, T, P> {
+        assert!(size != Zero::zero(), "chunk size must be non-zero");
+        Chunks {
+            slice: self,
+            size,
+        }
+    }
+
+    /// Returns an iterator over `size` elements of the slice at a time,
+    /// starting at the beginning of the slice. Every chunk has exactly
+    /// `size` elements; any leftover elements can be retrieved with
+    /// `ChunksExact::remainder`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn chunks_exact(self, size: I) -> ChunksExact<'a, K, I, T, P> {
+        assert!(size != Zero::zero(), "chunk size must be non-zero");
+        let rem = from_usize(to_usize(self.len) % to_usize(size));
+        let (slice, remainder) = self.split_at(self.len - rem);
+        ChunksExact {
+            slice,
+            remainder,
+            size,
+        }
+    }
+
+    /// Returns an iterator over `size` elements of the slice at a time,
+    /// starting at the end of the slice. The chunks don't overlap,
+    /// and the last chunk will be shorter if `size` does not divide
+    /// the length of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn rchunks(self, size: I) -> RChunks<'a, K, I, T, P> {
+        assert!(size != Zero::zero(), "chunk size must be non-zero");
+        RChunks {
+            slice: self,
+            size,
+        }
+    }
+
+    /// Returns an iterator over `size` elements of the slice at a time,
+    /// starting at the end of the slice. Every chunk has exactly
+    /// `size` elements; any leftover elements at the beginning of the slice
+    /// can be retrieved with `RChunksExact::remainder`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn rchunks_exact(self, size: I) -> RChunksExact<'a, K, I, T, P> {
+        assert!(size != Zero::zero(), "chunk size must be non-zero");
+        let rem = from_usize(to_usize(self.len) % to_usize(size));
+        let (remainder, slice) = self.split_at(rem);
+        RChunksExact {
+            slice,
+            remainder,
+            size,
+        }
+    }
+
+    /// Returns an iterator over each pair of consecutive elements,
+    /// like `windows(2)` but yielding tuples.
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use owned_slice::TakeSlice;
+    ///
+    /// let readings: VecDeque<i32> = vec![10, 13, 11, 20].into_iter().collect();
+    /// let deltas: Vec<i32> = readings.index_range_full(..).pairwise().map(|(a, b)| b - a).collect();
+    /// assert_eq!(deltas, [3, -2, 9]);
+    /// ```
+    pub fn pairwise(self) -> Pairwise<'a, K, I, T, P> {
+        let one: I = One::one();
+        Pairwise {
+            windows: self.windows(one + one),
+        }
+    }
+
+    /// Returns an iterator over each run of three consecutive elements,
+    /// like `windows(3)` but yielding tuples.
+    pub fn triplewise(self) -> Triplewise<'a, K, I, T, P> {
+        let one: I = One::one();
+        Triplewise {
+            windows: self.windows(one + one + one),
+        }
+    }
+}
+
+impl<'a, K, I, T, P> SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Returns an iterator over `size` elements of the slice at a time,
+    /// as disjoint mutable slices which may all be used at once.
+    /// The last chunk will be shorter if `size` does not divide
+    /// the length of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn chunks_mut(self, size: I) -> ChunksMut<'a, K, I, T, P>
+        where K: UniqueIndexMut<I>
+    {
+        assert!(size != Zero::zero(), "chunk size must be non-zero");
+        ChunksMut {
+            slice: self,
+            size,
+        }
+    }
+}
+
+/// An iterator over overlapping sub-slices of length `size`.
+///
+/// Returned by `Slice::windows`.
+pub struct Windows<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a, P: BoundsPolicy = Checked> {
+    slice: Slice<'a, K, I, T, P>,
+    size: I,
+}
+
+impl<'a, K, I, T, P> Iterator for Windows<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    type Item = Slice<'a, K, I, T, P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.slice.len < self.size {
+            None
+        } else {
+            let start = self.slice.start;
+            let window = Slice::from_range(self.slice.list, start..start + self.size);
+            self.slice.start = start + One::one();
+            self.slice.len = self.slice.len - One::one();
+            Some(window)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.slice.len < self.size {
+            (0, Some(0))
+        } else {
+            // a window starts at each of `0..=len - size`
+            let n = to_usize(self.slice.len - self.size) + 1;
+            (n, Some(n))
+        }
+    }
+}
+
+impl<'a, K, I, T, P> DoubleEndedIterator for Windows<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.slice.len < self.size {
+            None
+        } else {
+            let end = self.slice.start + self.slice.len;
+            let window = Slice::from_range(self.slice.list, end - self.size..end);
+            self.slice.len = self.slice.len - One::one();
+            Some(window)
+        }
+    }
+}
+
+impl<'a, K, I, T, P> ExactSizeIterator for Windows<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+}
+
+/// Returns the element `offset` places into `window`, for as long as
+/// the underlying container is borrowed.
+#[inline]
+fn nth_of<'a, K, I, T, P>(window: Slice<'a, K, I, T, P>, offset: usize) -> &'a T
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    &window.list[window.start + from_usize(offset)]
+}
+
+/// An iterator over pairs of consecutive elements.
+///
+/// Returned by `Slice::pairwise`.
+pub struct Pairwise<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a, P: BoundsPolicy = Checked> {
+    windows: Windows<'a, K, I, T, P>,
+}
+
+impl<'a, K, I, T, P> Iterator for Pairwise<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    type Item = (&'a T, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.windows.next().map(|w| (nth_of(w, 0), nth_of(w, 1)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.windows.size_hint()
+    }
+}
+
+impl<'a, K, I, T, P> DoubleEndedIterator for Pairwise<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.windows.next_back().map(|w| (nth_of(w, 0), nth_of(w, 1)))
+    }
+}
+
+impl<'a, K, I, T, P> ExactSizeIterator for Pairwise<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+}
+
+/// An iterator over runs of three consecutive elements.
+///
+/// Returned by `Slice::triplewise`.
+pub struct Triplewise<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a, P: BoundsPolicy = Checked> {
+    windows: Windows<'a, K, I, T, P>,
+}
+
+impl<'a, K, I, T, P> Iterator for Triplewise<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    type Item = (&'a T, &'a T, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.windows.next().map(|w| (nth_of(w, 0), nth_of(w, 1), nth_of(w, 2)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.windows.size_hint()
+    }
+}
+
+impl<'a, K, I, T, P> DoubleEndedIterator for Triplewise<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.windows.next_back().map(|w| (nth_of(w, 0), nth_of(w, 1), nth_of(w, 2)))
+    }
+}
+
+impl<'a, K, I, T, P> ExactSizeIterator for Triplewise<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+}
+
+/// An iterator over non-overlapping sub-slices of length `size`,
+/// the last of which may be shorter.
+///
+/// Returned by `Slice::chunks`.
+pub struct Chunks<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a, P: BoundsPolicy = Checked> {
+    slice: Slice<'a, K, I, T, P>,
+    size: I,
+}
+
+impl<'a, K, I, T, P> Iterator for Chunks<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    type Item = Slice<'a, K, I, T, P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            None
+        } else {
+            let size = if self.slice.len < self.size { self.slice.len } else { self.size };
+            let (chunk, rest) = self.slice.split_at(size);
+            self.slice = rest;
+            Some(chunk)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = to_usize(self.slice.len);
+        let size = to_usize(self.size);
+        let n = len.div_ceil(size);
+        (n, Some(n))
+    }
+}
+
+impl<'a, K, I, T, P> DoubleEndedIterator for Chunks<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            None
+        } else {
+            let rem = from_usize(to_usize(self.slice.len) % to_usize(self.size));
+            let size = if rem == Zero::zero() { self.size } else { rem };
+            let (rest, chunk) = self.slice.split_at(self.slice.len - size);
+            self.slice = rest;
+            Some(chunk)
+        }
+    }
+}
+
+impl<'a, K, I, T, P> ExactSizeIterator for Chunks<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+}
+
+/// An iterator over non-overlapping sub-slices of exactly `size` elements.
+///
+/// Returned by `Slice::chunks_exact`.
+pub struct ChunksExact<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a, P: BoundsPolicy = Checked> {
+    slice: Slice<'a, K, I, T, P>,
+    remainder: Slice<'a, K, I, T, P>,
+    size: I,
+}
+
+impl<'a, K, I, T, P> ChunksExact<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Returns the elements at the end of the slice which
+    /// did not fit into a whole chunk.
+    pub fn remainder(&self) -> Slice<'a, K, I, T, P> {
+        self.remainder
+    }
+}
+
+impl<'a, K, I, T, P> Iterator for ChunksExact<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    type Item = Slice<'a, K, I, T, P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            None
+        } else {
+            let (chunk, rest) = self.slice.split_at(self.size);
+            self.slice = rest;
+            Some(chunk)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = to_usize(self.slice.len) / to_usize(self.size);
+        (n, Some(n))
+    }
+}
+
+impl<'a, K, I, T, P> DoubleEndedIterator for ChunksExact<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            None
+        } else {
+            let (rest, chunk) = self.slice.split_at(self.slice.len - self.size);
+            self.slice = rest;
+            Some(chunk)
+        }
+    }
+}
+
+impl<'a, K, I, T, P> ExactSizeIterator for ChunksExact<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+}
+
+/// An iterator over non-overlapping sub-slices of length `size`,
+/// starting from the end of the slice. The last chunk may be shorter.
+///
+/// Returned by `Slice::rchunks`.
+pub struct RChunks<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a, P: BoundsPolicy = Checked> {
+    slice: Slice<'a, K, I, T, P>,
+    size: I,
+}
+
+impl<'a, K, I, T, P> Iterator for RChunks<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    type Item = Slice<'a, K, I, T, P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            None
+        } else {
+            let size = if self.slice.len < self.size { self.slice.len } else { self.size };
+            let (rest, chunk) = self.slice.split_at(self.slice.len - size);
+            self.slice = rest;
+            Some(chunk)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = to_usize(self.slice.len).div_ceil(to_usize(self.size));
+        (n, Some(n))
+    }
+}
+
+impl<'a, K, I, T, P> DoubleEndedIterator for RChunks<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            None
+        } else {
+            let rem = from_usize(to_usize(self.slice.len) % to_usize(self.size));
+            let size = if rem == Zero::zero() { self.size } else { rem };
+            let (chunk, rest) = self.slice.split_at(size);
+            self.slice = rest;
+            Some(chunk)
+        }
+    }
+}
+
+impl<'a, K, I, T, P> ExactSizeIterator for RChunks<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+}
+
+/// An iterator over non-overlapping sub-slices of exactly `size` elements,
+/// starting from the end of the slice.
+///
+/// Returned by `Slice::rchunks_exact`.
+pub struct RChunksExact<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a, P: BoundsPolicy = Checked> {
+    slice: Slice<'a, K, I, T, P>,
+    remainder: Slice<'a, K, I, T, P>,
+    size: I,
+}
+
+impl<'a, K, I, T, P> RChunksExact<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Returns the elements at the beginning of the slice which
+    /// did not fit into a whole chunk.
+    pub fn remainder(&self) -> Slice<'a, K, I, T, P> {
+        self.remainder
+    }
+}
+
+impl<'a, K, I, T, P> Iterator for RChunksExact<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    type Item = Slice<'a, K, I, T, P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            None
+        } else {
+            let (rest, chunk) = self.slice.split_at(self.slice.len - self.size);
+            self.slice = rest;
+            Some(chunk)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = to_usize(self.slice.len) / to_usize(self.size);
+        (n, Some(n))
+    }
+}
+
+impl<'a, K, I, T, P> DoubleEndedIterator for RChunksExact<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            None
+        } else {
+            let (chunk, rest) = self.slice.split_at(self.size);
+            self.slice = rest;
+            Some(chunk)
+        }
+    }
+}
+
+impl<'a, K, I, T, P> ExactSizeIterator for RChunksExact<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+}
+
+/// An iterator over non-overlapping mutable sub-slices of length `size`,
+/// the last of which may be shorter.
+///
+/// Returned by `SliceMut::chunks_mut`.
+pub struct ChunksMut<'a, K: 'a + ?Sized + IndexMut<I, Output = T>, I: 'a + Idx, T: 'a, P: BoundsPolicy = Checked> {
+    slice: SliceMut<'a, K, I, T, P>,
+    size: I,
+}
+
+impl<'a, K, I, T, P> ChunksMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    // Carves `size` elements off the slice, starting at `offset`.
+    // Chunks never overlap, and `K: ?Sized + UniqueIndexMut` ensures they can be
+    // used at the same time.
+    fn chunk(&self, offset: I, size: I) -> SliceMut<'a, K, I, T, P> {
+        SliceMut {
+            list: self.slice.list,
+            start: self.slice.start + offset,
+            len: size,
+            ty: marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, K, I, T, P> Iterator for ChunksMut<'a, K, I, T, P>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    type Item = SliceMut<'a, K, I, T, P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            None
+        } else {
+            let size = if self.slice.len < self.size { self.slice.len } else { self.size };
+            let chunk = self.chunk(Zero::zero(), size);
+            self.slice.start = self.slice.start + size;
+            self.slice.len = self.slice.len - size;
+            Some(chunk)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = to_usize(self.slice.len).div_ceil(to_usize(self.size));
+        (n, Some(n))
+    }
+}
+
+impl<'a, K, I, T, P> DoubleEndedIterator for ChunksMut<'a, K, I, T, P>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            None
+        } else {
+            let rem = from_usize(to_usize(self.slice.len) % to_usize(self.size));
+            let size = if rem == Zero::zero() { self.size } else { rem };
+            self.slice.len = self.slice.len - size;
+            let len = self.slice.len;
+            Some(self.chunk(len, size))
+        }
+    }
+}
+
+impl<'a, K, I, T, P> ExactSizeIterator for ChunksMut<'a, K, I, T, P>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use TakeSlice;
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..7).collect()
+    }
+
+    #[test]
+    fn windows() {
+        let v = test_vec();
+        let windows: Vec<Vec<usize>> = v.index_range(1..5)
+            .windows(2)
+            .map(|w| w.iter().cloned().collect())
+            .collect();
+        assert_eq!(windows, vec![vec![1, 2], vec![2, 3], vec![3, 4]]);
+        assert_eq!(v.index_range(1..5).windows(2).len(), 3);
+        assert!(v.index_range(1..5).windows(5).next().is_none());
+        assert_eq!(v.index_range(1..5).windows(2).next_back().map(|w| w[0]), Some(3));
+    }
+
+    struct Bytes(VecDeque<u8>);
+
+    impl ::std::ops::Index<u8> for Bytes {
+        type Output = u8;
+        fn index(&self, index: u8) -> &u8 {
+            &self.0[index as usize]
+        }
+    }
+
+    impl TakeSlice<u8, u8> for Bytes {
+        fn len(&self) -> u8 {
+            self.0.len() as u8
+        }
+    }
+
+    #[test]
+    fn windows_at_index_max() {
+        let b = Bytes((0..u8::MAX).collect());
+        assert_eq!(b.index_range_full(..).windows(1).len(), 255);
+        assert_eq!(b.index_range_full(..).windows(u8::MAX).len(), 1);
+        assert_eq!(b.index_range_full(..).pairwise().next_back(), Some((&253, &254)));
+    }
+
+    #[test]
+    fn pairwise() {
+        let v = test_vec();
+        let pairs: Vec<(usize, usize)> = v.index_range(2..5).pairwise().map(|(&a, &b)| (a, b)).collect();
+        assert_eq!(pairs, [(2, 3), (3, 4)]);
+        assert_eq!(v.index_range_full(..).pairwise().len(), 6);
+        assert_eq!(v.index_range_to(..1).pairwise().next(), None);
+        assert_eq!(v.index_range(2..6).triplewise().next_back(), Some((&3, &4, &5)));
+        assert_eq!(v.index_range(2..4).triplewise().len(), 0);
+    }
+
+    fn collect<'a, It>(iter: It) -> Vec<Vec<usize>>
+        where It: Iterator<Item = ::Slice<'a, VecDeque<usize>, usize, usize>>
+    {
+        iter.map(|s| s.iter().cloned().collect()).collect()
+    }
+
+    #[test]
+    fn chunks() {
+        let v = test_vec();
+        assert_eq!(collect(v.index_range(0..7).chunks(3)),
+                   vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+        assert_eq!(collect(v.index_range(0..7).chunks(3).rev()),
+                   vec![vec![6], vec![3, 4, 5], vec![0, 1, 2]]);
+        assert_eq!(v.index_range(0..7).chunks(3).len(), 3);
+        assert_eq!(v.index_range(0..6).chunks(3).len(), 2);
+        assert_eq!(v.index_range(0..0).chunks(3).len(), 0);
+    }
+
+    #[test]
+    fn chunks_exact() {
+        let v = test_vec();
+        let chunks = v.index_range(0..7).chunks_exact(3);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks.remainder().iter().cloned().collect::<Vec<_>>(), vec![6]);
+        assert_eq!(collect(chunks), vec![vec![0, 1, 2], vec![3, 4, 5]]);
+        assert_eq!(collect(v.index_range(0..7).chunks_exact(3).rev()),
+                   vec![vec![3, 4, 5], vec![0, 1, 2]]);
+    }
+
+    #[test]
+    fn rchunks() {
+        let v = test_vec();
+        assert_eq!(collect(v.index_range(0..7).rchunks(3)),
+                   vec![vec![4, 5, 6], vec![1, 2, 3], vec![0]]);
+        assert_eq!(collect(v.index_range(0..7).rchunks(3).rev()),
+                   vec![vec![0], vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(v.index_range(0..7).rchunks(3).len(), 3);
+    }
+
+    #[test]
+    fn rchunks_exact() {
+        let v = test_vec();
+        let chunks = v.index_range(0..7).rchunks_exact(3);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks.remainder().iter().cloned().collect::<Vec<_>>(), vec![0]);
+        assert_eq!(collect(chunks), vec![vec![4, 5, 6], vec![1, 2, 3]]);
+        assert_eq!(collect(v.index_range(0..7).rchunks_exact(3).rev()),
+                   vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    }
+
+    #[test]
+    fn chunks_mut() {
+        let mut v = test_vec();
+        let mut chunks: Vec<_> = v.index_range_mut(0..7).chunks_mut(3).collect();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[2].len(), 1);
+        let (first, rest) = chunks.split_at_mut(1);
+        ::std::mem::swap(&mut first[0][0], &mut rest[1][0]);
+        drop(chunks);
+        assert_eq!(v, vec![6, 1, 2, 3, 4, 5, 0]);
+        for (i, mut chunk) in v.index_range_mut(0..7).chunks_mut(3).rev().enumerate() {
+            chunk[0] = i;
+        }
+        assert_eq!(v, vec![2, 1, 2, 1, 4, 5, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn windows_of_zero() {
+        let v = test_vec();
+        v.index_range(1..5).windows(0);
+    }
+}
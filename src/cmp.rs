@@ -0,0 +1,327 @@
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::ops::{Index, IndexMut};
+use super::{Idx, Slice, SliceMut, BoundsPolicy};
+use util::to_usize;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Compares two slices element by element, like `[A] == [B]`.
+fn slices_eq<K1, I1, A, P1, K2, I2, B, P2>(a: &Slice<K1, I1, A, P1>, b: &Slice<K2, I2, B, P2>) -> bool
+    where K1: ?Sized + Index<I1, Output = A>,
+          I1: Idx,
+          P1: BoundsPolicy,
+          K2: ?Sized + Index<I2, Output = B>,
+          I2: Idx,
+          P2: BoundsPolicy,
+          A: PartialEq<B>
+{
+    to_usize(a.len) == to_usize(b.len) && a.iter().zip(b.iter()).all(|(x, y)| x == y)
+}
+
+impl<'a, 'b, K1, I1, A, P1, K2, I2, B, P2> PartialEq<Slice<'b, K2, I2, B, P2>> for Slice<'a, K1, I1, A, P1>
+    where K1: ?Sized + Index<I1, Output = A>,
+          I1: Idx,
+          P1: BoundsPolicy,
+          K2: ?Sized + Index<I2, Output = B>,
+          I2: Idx,
+          P2: BoundsPolicy,
+          A: PartialEq<B>
+{
+    fn eq(&self, other: &Slice<'b, K2, I2, B, P2>) -> bool {
+        slices_eq(self, other)
+    }
+}
+
+impl<'a, 'b, K1, I1, A, P1, K2, I2, B, P2> PartialEq<SliceMut<'b, K2, I2, B, P2>> for Slice<'a, K1, I1, A, P1>
+    where K1: ?Sized + Index<I1, Output = A>,
+          I1: Idx,
+          P1: BoundsPolicy,
+          K2: ?Sized + IndexMut<I2, Output = B>,
+          I2: Idx,
+          P2: BoundsPolicy,
+          A: PartialEq<B>
+{
+    fn eq(&self, other: &SliceMut<'b, K2, I2, B, P2>) -> bool {
+        slices_eq(self, &other.as_slice())
+    }
+}
+
+impl<'a, 'b, K1, I1, A, P1, K2, I2, B, P2> PartialEq<Slice<'b, K2, I2, B, P2>> for SliceMut<'a, K1, I1, A, P1>
+    where K1: ?Sized + IndexMut<I1, Output = A>,
+          I1: Idx,
+          P1: BoundsPolicy,
+          K2: ?Sized + Index<I2, Output = B>,
+          I2: Idx,
+          P2: BoundsPolicy,
+          A: PartialEq<B>
+{
+    fn eq(&self, other: &Slice<'b, K2, I2, B, P2>) -> bool {
+        slices_eq(&self.as_slice(), other)
+    }
+}
+
+impl<'a, 'b, K1, I1, A, P1, K2, I2, B, P2> PartialEq<SliceMut<'b, K2, I2, B, P2>> for SliceMut<'a, K1, I1, A, P1>
+    where K1: ?Sized + IndexMut<I1, Output = A>,
+          I1: Idx,
+          P1: BoundsPolicy,
+          K2: ?Sized + IndexMut<I2, Output = B>,
+          I2: Idx,
+          P2: BoundsPolicy,
+          A: PartialEq<B>
+{
+    fn eq(&self, other: &SliceMut<'b, K2, I2, B, P2>) -> bool {
+        slices_eq(&self.as_slice(), &other.as_slice())
+    }
+}
+
+/// Compares a slice view against a built-in slice.
+fn eq_builtin<K, I, A, B, P>(a: &Slice<K, I, A, P>, b: &[B]) -> bool
+    where K: ?Sized + Index<I, Output = A>,
+          I: Idx,
+          P: BoundsPolicy,
+          A: PartialEq<B>
+{
+    to_usize(a.len) == b.len() && a.iter().zip(b).all(|(x, y)| x == y)
+}
+
+macro_rules! impl_eq_builtin {
+    ($([$($params:tt)*] $rhs:ty;)*) => {$(
+        impl<'a, $($params)* K, I, A, B, P> PartialEq<$rhs> for Slice<'a, K, I, A, P>
+            where K: ?Sized + Index<I, Output = A>,
+                  I: Idx,
+                  P: BoundsPolicy,
+                  A: PartialEq<B>
+        {
+            fn eq(&self, other: &$rhs) -> bool {
+                eq_builtin(self, &other[..])
+            }
+        }
+
+        impl<'a, $($params)* K, I, A, B, P> PartialEq<$rhs> for SliceMut<'a, K, I, A, P>
+            where K: ?Sized + IndexMut<I, Output = A>,
+                  I: Idx,
+                  P: BoundsPolicy,
+                  A: PartialEq<B>
+        {
+            fn eq(&self, other: &$rhs) -> bool {
+                eq_builtin(&self.as_slice(), &other[..])
+            }
+        }
+    )*}
+}
+
+impl_eq_builtin! {
+    [] [B];
+    ['b,] &'b [B];
+    ['b,] &'b mut [B];
+    [const N: usize,] [B; N];
+}
+
+#[cfg(feature = "alloc")]
+impl_eq_builtin! {
+    [] Vec<B>;
+}
+
+impl<'a, K, I, T, P> Eq for Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy,
+          T: Eq
+{
+}
+
+impl<'a, K, I, T, P> Eq for SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy,
+          T: Eq
+{
+}
+
+impl<'a, 'b, K1, I1, A, P1, K2, I2, B, P2> PartialOrd<Slice<'b, K2, I2, B, P2>> for Slice<'a, K1, I1, A, P1>
+    where K1: ?Sized + Index<I1, Output = A>,
+          I1: Idx,
+          P1: BoundsPolicy,
+          K2: ?Sized + Index<I2, Output = B>,
+          I2: Idx,
+          P2: BoundsPolicy,
+          A: PartialOrd<B>
+{
+    fn partial_cmp(&self, other: &Slice<'b, K2, I2, B, P2>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<'a, 'b, K1, I1, A, P1, K2, I2, B, P2> PartialOrd<SliceMut<'b, K2, I2, B, P2>> for SliceMut<'a, K1, I1, A, P1>
+    where K1: ?Sized + IndexMut<I1, Output = A>,
+          I1: Idx,
+          P1: BoundsPolicy,
+          K2: ?Sized + IndexMut<I2, Output = B>,
+          I2: Idx,
+          P2: BoundsPolicy,
+          A: PartialOrd<B>
+{
+    fn partial_cmp(&self, other: &SliceMut<'b, K2, I2, B, P2>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<'a, K, I, A, B, P> PartialOrd<[B]> for Slice<'a, K, I, A, P>
+    where K: ?Sized + Index<I, Output = A>,
+          I: Idx,
+          P: BoundsPolicy,
+          A: PartialOrd<B>
+{
+    fn partial_cmp(&self, other: &[B]) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<'a, 'b, K, I, A, B, P> PartialOrd<&'b [B]> for Slice<'a, K, I, A, P>
+    where K: ?Sized + Index<I, Output = A>,
+          I: Idx,
+          P: BoundsPolicy,
+          A: PartialOrd<B>
+{
+    fn partial_cmp(&self, other: &&'b [B]) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<'a, K, I, T, P> Ord for Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy,
+          T: Ord
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<'a, K, I, T, P> Ord for SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy,
+          T: Ord
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<'a, K, I, T, P> Hash for Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy,
+          T: Hash
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // hash the length first so that slices like [[1], [2, 3]] and
+        // [[1, 2], [3]] hash differently, just like `[T]` does.
+        state.write_usize(to_usize(self.len));
+        for x in self.iter() {
+            x.hash(state);
+        }
+    }
+}
+
+impl<'a, K, I, T, P> Hash for SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy,
+          T: Hash
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashSet, VecDeque};
+    use std::collections::hash_map::DefaultHasher;
+    use std::cmp::Ordering;
+    use std::hash::{Hash, Hasher};
+    use std::ops::Index;
+    use TakeSlice;
+
+    // a container which stores its elements in reverse order
+    struct Backwards(Vec<u32>);
+
+    impl Index<u8> for Backwards {
+        type Output = u32;
+        fn index(&self, index: u8) -> &u32 {
+            &self.0[self.0.len() - 1 - index as usize]
+        }
+    }
+
+    #[test]
+    fn equality_across_backends() {
+        let mut v: VecDeque<u32> = vec![1, 2, 3, 4].into_iter().collect();
+        let b = Backwards(vec![4, 3, 2, 1]);
+        let backwards = ::Slice::new(&b, 0..3);
+        assert!(v.index_range(0..3) == backwards);
+        assert!(v.index_range(1..4) != backwards);
+        assert!(v.index_range(0..2) != backwards);
+        assert_eq!(v.index_range(0..2), v.index_range(0..2));
+        let mut w = v.clone();
+        assert!(v.index_range_mut(1..3) == w.index_range_mut(1..3));
+        assert!(v.index_range_mut(1..3) == backwards.split_at(1).1);
+        assert!(backwards != w.index_range_mut(0..2));
+    }
+
+    #[test]
+    fn equality_with_builtin_slices() {
+        let mut v: VecDeque<u32> = vec![1, 2, 3, 4].into_iter().collect();
+        assert_eq!(v.index_range(1..4), [2, 3, 4]);
+        assert_eq!(v.index_range(1..4), vec![2, 3, 4]);
+        assert_eq!(v.index_range(1..4), &[2, 3, 4][..]);
+        assert!(v.index_range(1..4) != [2, 3]);
+        assert!(v.index_range(1..4) != [2, 3, 5]);
+        let mut s = v.index_range_mut(0..2);
+        s[1] = 7;
+        assert!(s == [1, 7]);
+        assert!(s == vec![1, 7]);
+        assert!(s == *[1, 7].as_ref());
+    }
+
+    fn hash_of<H: Hash>(x: &H) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        x.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn hashing() {
+        let mut v: VecDeque<u32> = vec![1, 2, 1, 2, 3].into_iter().collect();
+        let b = Backwards(vec![2, 1]);
+        assert_eq!(hash_of(&v.index_range(0..2)), hash_of(&v.index_range(2..4)));
+        assert_eq!(hash_of(&v.index_range(0..2)), hash_of(&::Slice::new(&b, 0..2)));
+        assert!(hash_of(&v.index_range(0..2)) != hash_of(&v.index_range(1..3)));
+        let set: HashSet<_> = v.index_range(0..5).windows(2).collect();
+        assert_eq!(set.len(), 3);
+        let expected = hash_of(&v.index_range(3..5));
+        assert_eq!(hash_of(&v.index_range_mut(3..5)), expected);
+    }
+
+    #[test]
+    fn lexicographic_ordering() {
+        let v: VecDeque<u32> = vec![1, 2, 3, 1, 2, 4, 1, 2].into_iter().collect();
+        assert!(v.index_range(0..3) < v.index_range(3..6));
+        assert!(v.index_range(6..8) < v.index_range(0..3));
+        assert!(v.index_range(0..2) <= v.index_range(6..8));
+        assert_eq!(v.index_range(0..2).cmp(&v.index_range(6..8)), Ordering::Equal);
+        assert!(v.index_range(0..3) > [1, 2][..]);
+        assert!(v.index_range(0..3) < *[1, 3].as_ref());
+        let b = Backwards(vec![9, 1]);
+        assert!(v.index_range(0..2) < ::Slice::new(&b, 0..2));
+        let mut windows: Vec<_> = v.index_range(0..8).windows(2).collect();
+        windows.sort();
+        assert_eq!(windows[0], [1, 2]);
+        assert_eq!(windows[windows.len() - 1], [4, 1]);
+        let (mut x, mut y) = (v.clone(), v.clone());
+        assert!(x.index_range_mut(0..3) < y.index_range_mut(3..6));
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+use core::ops::Index;
+use super::{Idx, Slice, BoundsPolicy};
+#[cfg(feature = "alloc")]
+use util::to_usize;
+
+impl<'a, K, I, T, P> Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy,
+          T: Clone
+{
+    /// Clones the elements of the slice into a new `Vec`.
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> Vec<T> {
+        let mut vec = Vec::with_capacity(to_usize(self.len));
+        vec.extend(self.iter().cloned());
+        vec
+    }
+
+    /// Clones the elements of the slice into a new `VecDeque`.
+    #[cfg(feature = "alloc")]
+    pub fn to_vec_deque(&self) -> VecDeque<T> {
+        let mut deque = VecDeque::with_capacity(to_usize(self.len));
+        deque.extend(self.iter().cloned());
+        deque
+    }
+
+    /// Clones the elements of the slice into any collection.
+    /// The iterator passed to `FromIterator` reports its exact length,
+    /// so collections can reserve space up front.
+    pub fn collect_into<C: FromIterator<T>>(&self) -> C {
+        self.iter().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, VecDeque};
+    use TakeSlice;
+
+    #[test]
+    fn to_owned_collections() {
+        let v: VecDeque<u8> = vec![3, 1, 4, 1, 5].into_iter().collect();
+        let s = v.index_range(1..4);
+        let vec = s.to_vec();
+        assert_eq!(vec, vec![1, 4, 1]);
+        assert_eq!(vec.capacity(), 3);
+        assert_eq!(s.to_vec_deque(), vec![1, 4, 1]);
+        let set: BTreeSet<u8> = s.collect_into();
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), vec![1, 4]);
+        assert!(v.index_range(0..0).to_vec().is_empty());
+    }
+}
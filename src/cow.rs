@@ -0,0 +1,192 @@
+use alloc::vec::Vec;
+use core::fmt::{self, Debug};
+use core::ops::{Index, IndexMut};
+use num_traits::Zero;
+use util::{to_usize, from_usize};
+use {BoundsPolicy, Idx, Iter, Slice, TakeSlice};
+
+/// A clone-on-write slice.
+///
+/// It starts out borrowing a `Slice`, and clones the viewed elements into
+/// an owned `Vec` the first time it is mutated, so code which only
+/// sometimes modifies its window doesn't pay for a copy otherwise.
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use owned_slice::{CowSlice, TakeSlice};
+///
+/// let deque: VecDeque<u32> = (0..6).collect();
+/// let mut cow = CowSlice::from(deque.index_range(2..5));
+/// assert!(!cow.is_owned());
+/// assert_eq!(cow[0], 2);
+///
+/// cow[0] = 9;
+/// assert!(cow.is_owned());
+/// assert_eq!(cow.into_owned(), [9, 3, 4]);
+/// assert_eq!(deque[2], 2);
+/// ```
+pub enum CowSlice<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a> {
+    /// Still viewing the original container.
+    Borrowed(Slice<'a, K, I, T>),
+    /// A private copy of the viewed elements.
+    Owned(Vec<T>),
+}
+
+impl<'a, K, I, T> CowSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          T: Clone
+{
+    /// Returns the number of elements in the slice.
+    pub fn len(&self) -> I {
+        match *self {
+            CowSlice::Borrowed(ref slice) => slice.len(),
+            CowSlice::Owned(ref vec) => from_usize(vec.len()),
+        }
+    }
+
+    /// Returns `true` if the slice has a length of zero.
+    pub fn is_empty(&self) -> bool {
+        self.len() == Zero::zero()
+    }
+
+    /// Returns `true` if the elements have been cloned into an owned buffer.
+    pub fn is_owned(&self) -> bool {
+        match *self {
+            CowSlice::Borrowed(_) => false,
+            CowSlice::Owned(_) => true,
+        }
+    }
+
+    /// Returns a reference to the element at `index`,
+    /// or `None` if it is out of bounds.
+    pub fn get(&self, index: I) -> Option<&T> {
+        match *self {
+            CowSlice::Borrowed(ref slice) => slice.get(index),
+            CowSlice::Owned(ref vec) => vec.get(to_usize(index)),
+        }
+    }
+
+    /// Returns a mutable reference to the owned buffer,
+    /// cloning the borrowed elements into it first if needed.
+    pub fn to_mut(&mut self) -> &mut Vec<T> {
+        if let CowSlice::Borrowed(slice) = *self {
+            *self = CowSlice::Owned(slice.to_vec());
+        }
+        match *self {
+            CowSlice::Owned(ref mut vec) => vec,
+            CowSlice::Borrowed(_) => unreachable!(),
+        }
+    }
+
+    /// Extracts the owned elements, cloning them if they are still borrowed.
+    pub fn into_owned(self) -> Vec<T> {
+        match self {
+            CowSlice::Borrowed(slice) => slice.to_vec(),
+            CowSlice::Owned(vec) => vec,
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, Self, I, T> {
+        self.index_range_full(..).iter()
+    }
+}
+
+impl<'a, K, I, T, P> From<Slice<'a, K, I, T, P>> for CowSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    fn from(slice: Slice<'a, K, I, T, P>) -> Self {
+        CowSlice::Borrowed(slice.with_policy())
+    }
+}
+
+impl<'a, K, I, T> Clone for CowSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          T: Clone
+{
+    fn clone(&self) -> Self {
+        match *self {
+            CowSlice::Borrowed(slice) => CowSlice::Borrowed(slice),
+            CowSlice::Owned(ref vec) => CowSlice::Owned(vec.clone()),
+        }
+    }
+}
+
+impl<'a, K, I, T> Index<I> for CowSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          T: Clone
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: I) -> &T {
+        match *self {
+            CowSlice::Borrowed(ref slice) => &slice[index],
+            CowSlice::Owned(ref vec) => &vec[to_usize(index)],
+        }
+    }
+}
+
+impl<'a, K, I, T> IndexMut<I> for CowSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          T: Clone
+{
+    /// Clones the borrowed elements on first use.
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut T {
+        &mut self.to_mut()[to_usize(index)]
+    }
+}
+
+impl<'a, K, I, T> TakeSlice<T, I> for CowSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          T: Clone
+{
+    fn len(&self) -> I {
+        self.len()
+    }
+}
+
+impl<'a, K, I, T> Debug for CowSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          T: Clone + Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use {CowSlice, TakeSlice};
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..10).collect()
+    }
+
+    #[test]
+    fn clone_on_write() {
+        let v = test_vec();
+        let mut cow = CowSlice::from(v.index_range(3..7));
+        let shared = cow.clone();
+        assert_eq!(cow.iter().sum::<usize>(), 18);
+        assert!(!cow.is_owned());
+
+        cow.index_range_mut(1..3)[1] = 8;
+        assert!(cow.is_owned());
+        assert!(!shared.is_owned());
+        assert_eq!(format!("{:?}", cow), "[3, 4, 8, 6]");
+        assert_eq!(format!("{:?}", shared), "[3, 4, 5, 6]");
+        assert_eq!(v.index_range(3..7), [3, 4, 5, 6]);
+        cow.to_mut().push(7);
+        assert_eq!(cow.len(), 5);
+    }
+}
@@ -0,0 +1,310 @@
+use core::ops::{Index, IndexMut};
+use num_traits::{Zero, One};
+use iter::remaining;
+use util::assert_in_bounds;
+use {BoundsPolicy, Idx, Slice, SliceMut, UniqueIndexMut};
+
+/// A position within an immutable slice, for consuming it incrementally.
+/// Created with `Slice::cursor`.
+///
+/// Iterating over a cursor yields the remaining elements and advances it.
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use owned_slice::TakeSlice;
+///
+/// let buf: VecDeque<u8> = b"GET /".iter().cloned().collect();
+/// let mut cursor = buf.index_range_full(..).cursor();
+/// let method_len = cursor.rest().position(|&b| b == b' ').unwrap();
+/// cursor.advance(method_len);
+/// assert_eq!(cursor.consumed(), &b"GET"[..]);
+/// assert_eq!(cursor.next(), Some(&b' '));
+/// assert_eq!(cursor.peek(), Some(&b'/'));
+/// assert_eq!(cursor.remaining(), 1);
+/// ```
+pub struct Cursor<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a> {
+    slice: Slice<'a, K, I, T>,
+    pos: I,
+}
+
+/// A position within a mutable slice, for consuming it incrementally.
+/// Created with `SliceMut::cursor`.
+pub struct CursorMut<'a, K: 'a + ?Sized + IndexMut<I, Output = T>, I: 'a + Idx, T: 'a> {
+    slice: SliceMut<'a, K, I, T>,
+    pos: I,
+}
+
+impl<'a, K, I, T, P> Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Returns a cursor positioned at the start of the slice.
+    pub fn cursor(self) -> Cursor<'a, K, I, T> {
+        Cursor {
+            slice: self.with_policy(),
+            pos: Zero::zero(),
+        }
+    }
+}
+
+impl<'a, K, I, T, P> SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Returns a mutable cursor positioned at the start of the slice.
+    pub fn cursor(self) -> CursorMut<'a, K, I, T> {
+        CursorMut {
+            slice: self.with_policy(),
+            pos: Zero::zero(),
+        }
+    }
+}
+
+impl<'a, K, I, T> Cursor<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    /// Returns the number of elements consumed so far.
+    #[inline]
+    pub fn position(&self) -> I {
+        self.pos
+    }
+
+    /// Returns the number of elements left to consume.
+    #[inline]
+    pub fn remaining(&self) -> I {
+        self.slice.len() - self.pos
+    }
+
+    /// Returns `true` if every element has been consumed.
+    #[inline]
+    pub fn is_at_end(&self) -> bool {
+        self.pos == self.slice.len()
+    }
+
+    /// Moves the cursor to `pos`, which may be equal to the length of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos` is out of bounds.
+    pub fn seek(&mut self, pos: I) {
+        assert_in_bounds(&(Zero::zero()..pos), self.slice.len());
+        self.pos = pos;
+    }
+
+    /// Moves the cursor forward by `n` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than `n` elements remain.
+    pub fn advance(&mut self, n: I) {
+        assert_in_bounds(&(Zero::zero()..n), self.remaining());
+        self.pos = self.pos + n;
+    }
+
+    /// Returns the next element without consuming it.
+    #[inline]
+    pub fn peek(&self) -> Option<&'a T> {
+        self.slice.get(self.pos)
+    }
+
+    /// Returns the elements which have been consumed.
+    pub fn consumed(&self) -> Slice<'a, K, I, T> {
+        self.slice.subslice_to(..self.pos)
+    }
+
+    /// Returns the elements which have not been consumed yet.
+    pub fn rest(&self) -> Slice<'a, K, I, T> {
+        self.slice.subslice_from(self.pos..)
+    }
+
+    /// Splits the slice at the cursor into the consumed and unconsumed parts.
+    pub fn split(self) -> (Slice<'a, K, I, T>, Slice<'a, K, I, T>) {
+        self.slice.split_at(self.pos)
+    }
+}
+
+impl<'a, K, I, T> CursorMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx
+{
+    /// Returns the number of elements consumed so far.
+    #[inline]
+    pub fn position(&self) -> I {
+        self.pos
+    }
+
+    /// Returns the number of elements left to consume.
+    #[inline]
+    pub fn remaining(&self) -> I {
+        self.slice.len() - self.pos
+    }
+
+    /// Returns `true` if every element has been consumed.
+    #[inline]
+    pub fn is_at_end(&self) -> bool {
+        self.pos == self.slice.len()
+    }
+
+    /// Moves the cursor to `pos`, which may be equal to the length of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos` is out of bounds.
+    pub fn seek(&mut self, pos: I) {
+        assert_in_bounds(&(Zero::zero()..pos), self.slice.len());
+        self.pos = pos;
+    }
+
+    /// Moves the cursor forward by `n` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than `n` elements remain.
+    pub fn advance(&mut self, n: I) {
+        assert_in_bounds(&(Zero::zero()..n), self.remaining());
+        self.pos = self.pos + n;
+    }
+
+    /// Returns the next element without consuming it.
+    #[inline]
+    pub fn peek(&self) -> Option<&T> {
+        self.slice.get(self.pos)
+    }
+
+    /// Returns the next element mutably without consuming it.
+    #[inline]
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        let pos = self.pos;
+        self.slice.get_mut(pos)
+    }
+
+    /// Returns the next element mutably and advances past it,
+    /// or returns `None` at the end of the slice.
+    pub fn next_mut(&mut self) -> Option<&mut T> {
+        let pos = self.pos;
+        if pos < self.slice.len() {
+            self.pos = pos + One::one();
+        }
+        self.slice.get_mut(pos)
+    }
+
+    /// Returns the elements which have been consumed.
+    pub fn consumed(&self) -> Slice<'_, K, I, T> {
+        self.slice.as_slice().subslice_to(..self.pos)
+    }
+
+    /// Returns the elements which have been consumed, mutably.
+    pub fn consumed_mut(&mut self) -> SliceMut<'_, K, I, T> {
+        let pos = self.pos;
+        self.slice.subslice_to_mut(..pos)
+    }
+
+    /// Returns the elements which have not been consumed yet.
+    pub fn rest(&self) -> Slice<'_, K, I, T> {
+        self.slice.as_slice().subslice_from(self.pos..)
+    }
+
+    /// Returns the elements which have not been consumed yet, mutably.
+    pub fn rest_mut(&mut self) -> SliceMut<'_, K, I, T> {
+        let pos = self.pos;
+        self.slice.subslice_from_mut(pos..)
+    }
+
+    /// Splits the slice at the cursor into the consumed and unconsumed parts.
+    pub fn split(self) -> (SliceMut<'a, K, I, T>, SliceMut<'a, K, I, T>)
+        where K: UniqueIndexMut<I>
+    {
+        self.slice.split_at_mut(self.pos)
+    }
+}
+
+impl<'a, K, I, T> Clone for Cursor<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    fn clone(&self) -> Self {
+        Cursor {
+            slice: self.slice,
+            pos: self.pos,
+        }
+    }
+}
+
+impl<'a, K, I, T> Iterator for Cursor<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.slice.get(self.pos);
+        if item.is_some() {
+            self.pos = self.pos + One::one();
+        }
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        remaining(self.pos, self.slice.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use TakeSlice;
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..10).collect()
+    }
+
+    #[test]
+    fn cursor() {
+        let v = test_vec();
+        let mut c = v.index_range(2..8).cursor();
+        assert_eq!(c.peek(), Some(&2));
+        c.advance(2);
+        assert_eq!(c.next(), Some(&4));
+        assert_eq!((c.position(), c.remaining()), (3, 3));
+        assert_eq!(c.consumed(), [2, 3, 4]);
+        assert_eq!(c.rest(), [5, 6, 7]);
+        c.seek(6);
+        assert!(c.is_at_end());
+        assert_eq!(c.peek(), None);
+        assert_eq!(c.next(), None);
+        c.seek(1);
+        assert_eq!(c.clone().sum::<usize>(), 3 + 4 + 5 + 6 + 7);
+        let (done, rest) = c.split();
+        assert_eq!((done.len(), rest.len()), (1, 5));
+    }
+
+    #[test]
+    fn cursor_mut() {
+        let mut v = test_vec();
+        {
+            let mut c = v.index_range_to_mut(..5).cursor();
+            *c.next_mut().unwrap() = 10;
+            *c.peek_mut().unwrap() = 11;
+            c.advance(2);
+            c.consumed_mut()[2] = 12;
+            c.rest_mut()[1] = 14;
+            let (done, mut rest) = c.split();
+            assert_eq!(done, [10, 11, 12]);
+            rest[0] = 13;
+        }
+        assert_eq!(v.index_range_to(..6), [10, 11, 12, 13, 14, 5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn advance_past_end() {
+        let v = test_vec();
+        let mut c = v.index_range_full(..).cursor();
+        c.advance(8);
+        c.advance(3);
+    }
+}
@@ -0,0 +1,154 @@
+use core::ops::{Index, IndexMut};
+use {Idx, Slice, SliceMut, TakeSlice, UniqueIndexMut};
+
+/// An object-safe counterpart to `TakeSlice`.
+///
+/// Every sized `TakeSlice` container implements this trait, so differing
+/// containers can be stored side by side as `Box<dyn DynTakeSlice<T>>`.
+/// The trait object itself implements `TakeSlice`, so it can be sliced,
+/// iterated and searched just like the container behind it.
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use owned_slice::{DynTakeSlice, TakeSlice};
+///
+/// let deque: VecDeque<u8> = (0..4).collect();
+/// let containers: Vec<Box<dyn DynTakeSlice<u8>>> = vec![
+///     Box::new(vec![1, 2, 3]),
+///     Box::new([7, 8]),
+///     Box::new(deque),
+/// ];
+/// let sums: Vec<u8> = containers.iter()
+///     .map(|c| c.index_range_from(1..).iter().sum())
+///     .collect();
+/// assert_eq!(sums, [5, 8, 6]);
+/// ```
+pub trait DynTakeSlice<T, I = usize>
+    where I: Idx
+{
+    /// Returns the number of elements in the container.
+    fn dyn_len(&self) -> I;
+
+    /// Returns a reference to the element at `index`.
+    fn dyn_index(&self, index: I) -> &T;
+}
+
+/// The mutable counterpart to `DynTakeSlice`, implemented for every sized
+/// `TakeSlice` container which is also `UniqueIndexMut`.
+pub trait DynTakeSliceMut<T, I = usize>: DynTakeSlice<T, I>
+    where I: Idx
+{
+    /// Returns a mutable reference to the element at `index`.
+    fn dyn_index_mut(&mut self, index: I) -> &mut T;
+}
+
+/// An immutable view into a type-erased container.
+pub type DynSlice<'a, T, I = usize> = Slice<'a, dyn DynTakeSlice<T, I> + 'a, I, T>;
+
+/// A mutable view into a type-erased container.
+pub type DynSliceMut<'a, T, I = usize> = SliceMut<'a, dyn DynTakeSliceMut<T, I> + 'a, I, T>;
+
+impl<K, I, T> DynTakeSlice<T, I> for K
+    where K: TakeSlice<T, I>,
+          I: Idx
+{
+    fn dyn_len(&self) -> I {
+        self.len()
+    }
+
+    fn dyn_index(&self, index: I) -> &T {
+        &self[index]
+    }
+}
+
+impl<K, I, T> DynTakeSliceMut<T, I> for K
+    where K: TakeSlice<T, I> + UniqueIndexMut<I>,
+          I: Idx
+{
+    fn dyn_index_mut(&mut self, index: I) -> &mut T {
+        &mut self[index]
+    }
+}
+
+impl<'a, I, T> Index<I> for dyn DynTakeSlice<T, I> + 'a
+    where I: Idx
+{
+    type Output = T;
+    fn index(&self, index: I) -> &T {
+        self.dyn_index(index)
+    }
+}
+
+impl<'a, I, T> TakeSlice<T, I> for dyn DynTakeSlice<T, I> + 'a
+    where I: Idx
+{
+    fn len(&self) -> I {
+        self.dyn_len()
+    }
+}
+
+impl<'a, I, T> Index<I> for dyn DynTakeSliceMut<T, I> + 'a
+    where I: Idx
+{
+    type Output = T;
+    fn index(&self, index: I) -> &T {
+        self.dyn_index(index)
+    }
+}
+
+impl<'a, I, T> IndexMut<I> for dyn DynTakeSliceMut<T, I> + 'a
+    where I: Idx
+{
+    fn index_mut(&mut self, index: I) -> &mut T {
+        self.dyn_index_mut(index)
+    }
+}
+
+impl<'a, I, T> TakeSlice<T, I> for dyn DynTakeSliceMut<T, I> + 'a
+    where I: Idx
+{
+    fn len(&self) -> I {
+        self.dyn_len()
+    }
+}
+
+// `DynTakeSliceMut` is only implemented for containers which are `UniqueIndexMut`.
+unsafe impl<'a, I, T> UniqueIndexMut<I> for dyn DynTakeSliceMut<T, I> + 'a
+    where I: Idx
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use {DynSlice, DynTakeSlice, DynTakeSliceMut, TakeSlice};
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..5).collect()
+    }
+
+    #[test]
+    fn erased_views() {
+        let v = test_vec();
+        let erased: &dyn DynTakeSlice<usize> = &v;
+        let slice: DynSlice<usize> = erased.index_range(1..4);
+        assert_eq!(slice, [1, 2, 3]);
+        assert_eq!(slice.iter().max(), Some(&3));
+        assert_eq!(erased.len(), 5);
+    }
+
+    #[test]
+    fn erased_mut_views() {
+        let mut containers: Vec<Box<dyn DynTakeSliceMut<usize>>> = vec![
+            Box::new(test_vec()),
+            Box::new((5..8).collect::<VecDeque<_>>()),
+        ];
+        for c in containers.iter_mut() {
+            let (mut a, mut b) = c.index_range_full_mut(..).split_at_mut(1);
+            a[0] += b[0];
+            b.reverse();
+        }
+        assert_eq!(containers[0].index_range_full(..), [1, 4, 3, 2, 1]);
+        assert_eq!(containers[1].index_range_full(..), [11, 7, 6]);
+    }
+}
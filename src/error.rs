@@ -0,0 +1,55 @@
+#[cfg(feature = "std")]
+use std::error::Error;
+use core::fmt;
+use core::ops::Range;
+use super::Step;
+
+/// The error returned by the fallible `try_*` accessors when
+/// a range or index lies outside of the container or slice.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OutOfBounds<I: Step> {
+    /// The range that was requested.
+    /// Single-element accesses report `index..index + 1`.
+    /// Bounds which overflow the index type, like the end of `..=u8::MAX`,
+    /// are reported saturated.
+    pub range: Range<I>,
+    /// The length of the container or slice being indexed.
+    pub len: I,
+}
+
+impl<I: Step> fmt::Display for OutOfBounds<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Range out of bounds: {:?} is not a subset of {:?}",
+               self.range,
+               I::zero()..self.len)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I: Step> Error for OutOfBounds<I> {}
+
+#[inline]
+pub fn check_in_bounds<I: Step>(index: &Range<I>, len: I) -> Result<(), OutOfBounds<I>> {
+    if index.start > index.end || index.end > len {
+        Err(OutOfBounds {
+            range: index.clone(),
+            len,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+#[inline]
+pub fn check_index<I: Step>(index: I, len: I) -> Result<(), OutOfBounds<I>> {
+    if index < len {
+        Ok(())
+    } else {
+        Err(OutOfBounds {
+            range: index..index.checked_successor().unwrap_or(index),
+            len,
+        })
+    }
+}
+
@@ -0,0 +1,186 @@
+use alloc::vec::Vec;
+use core::fmt::{self, Debug};
+use core::marker;
+use core::ops::Index;
+use {Iter, TakeSlice};
+
+/// An immutable view which flattens a container of containers,
+/// such as a `VecDeque<Vec<T>>`, into a single index space.
+///
+/// The starting offset of every segment is cached when the view is
+/// created, so random access takes O(log n) time in the number of segments.
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use owned_slice::{FlatSlice, TakeSlice};
+///
+/// let mut rope = VecDeque::new();
+/// rope.push_back(vec!['a', 'b']);
+/// rope.push_back(vec![]);
+/// rope.push_back(vec!['c', 'd', 'e']);
+/// let flat = FlatSlice::new(&rope);
+/// assert_eq!(flat.len(), 5);
+/// assert_eq!(flat[2], 'c');
+/// assert_eq!(flat.index_range(1..4), ['b', 'c', 'd'][..]);
+/// ```
+pub struct FlatSlice<'a, K: 'a + ?Sized + Index<usize, Output = S>, S: 'a + Index<usize, Output = T>, T: 'a> {
+    list: &'a K,
+    /// `offsets[n]` is the flat index of the first element of segment `n`,
+    /// with one extra entry holding the total length.
+    offsets: Vec<usize>,
+    ty: marker::PhantomData<T>,
+}
+
+impl<'a, K, S, T> FlatSlice<'a, K, S, T>
+    where K: ?Sized + TakeSlice<S, usize>,
+          S: TakeSlice<T, usize>
+{
+    /// Creates a flattened view of `list`, measuring each of its segments.
+    pub fn new(list: &'a K) -> FlatSlice<'a, K, S, T> {
+        let mut offsets = Vec::with_capacity(list.len() + 1);
+        let mut total = 0;
+        offsets.push(total);
+        for segment in 0..list.len() {
+            total += list[segment].len();
+            offsets.push(total);
+        }
+        FlatSlice {
+            list,
+            offsets,
+            ty: marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, K, S, T> FlatSlice<'a, K, S, T>
+    where K: ?Sized + Index<usize, Output = S>,
+          S: Index<usize, Output = T>
+{
+    /// Returns the total number of elements in all segments.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.offsets[self.offsets.len() - 1]
+    }
+
+    /// Returns `true` if every segment is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of segments, including empty ones.
+    #[inline]
+    pub fn segments(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Maps a flat index to the segment containing it and the index within
+    /// that segment, or returns `None` if it is out of bounds.
+    pub fn locate(&self, index: usize) -> Option<(usize, usize)> {
+        if index >= self.len() {
+            return None;
+        }
+        // the last segment starting at or before `index`, which skips
+        // over any empty segments sharing its offset
+        let segment = self.offsets.partition_point(|&start| start <= index) - 1;
+        Some((segment, index - self.offsets[segment]))
+    }
+
+    /// Returns a reference to the element at the flat `index`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        let list = self.list;
+        self.locate(index).map(|(segment, offset)| &list[segment][offset])
+    }
+
+    pub fn iter(&self) -> Iter<'_, Self, usize, T> {
+        self.index_range_full(..).iter()
+    }
+}
+
+impl<'a, K, S, T> Clone for FlatSlice<'a, K, S, T>
+    where K: ?Sized + Index<usize, Output = S>,
+          S: Index<usize, Output = T>
+{
+    fn clone(&self) -> Self {
+        FlatSlice {
+            list: self.list,
+            offsets: self.offsets.clone(),
+            ty: marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, K, S, T> Index<usize> for FlatSlice<'a, K, S, T>
+    where K: ?Sized + Index<usize, Output = S>,
+          S: Index<usize, Output = T>
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, self.len()),
+        }
+    }
+}
+
+impl<'a, K, S, T> TakeSlice<T, usize> for FlatSlice<'a, K, S, T>
+    where K: ?Sized + Index<usize, Output = S>,
+          S: Index<usize, Output = T>
+{
+    fn len(&self) -> usize {
+        self.offsets[self.offsets.len() - 1]
+    }
+}
+
+impl<'a, K, S, T> Debug for FlatSlice<'a, K, S, T>
+    where K: ?Sized + Index<usize, Output = S>,
+          S: Index<usize, Output = T>,
+          T: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use super::FlatSlice;
+    use TakeSlice;
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..10).collect()
+    }
+
+    #[test]
+    fn flat() {
+        let v = test_vec();
+        let segments: VecDeque<Vec<usize>> = vec![
+            vec![],
+            v.index_range_to(..3).iter().cloned().collect(),
+            vec![],
+            vec![],
+            v.index_range_from(3..).iter().cloned().collect(),
+        ].into_iter().collect();
+        let flat = FlatSlice::new(&segments);
+        assert_eq!(flat.segments(), 5);
+        assert_eq!(flat.locate(2), Some((1, 2)));
+        assert_eq!(flat.locate(3), Some((4, 0)));
+        assert_eq!(flat.locate(10), None);
+        assert_eq!(flat.iter().cloned().collect::<VecDeque<_>>(), v);
+        assert_eq!(flat.index_range(2..5), [2, 3, 4]);
+    }
+
+    #[test]
+    fn flat_empty() {
+        let segments: Vec<Vec<u8>> = vec![vec![], vec![]];
+        let flat = FlatSlice::new(&segments);
+        assert!(flat.is_empty());
+        assert_eq!(flat.get(0), None);
+        assert_eq!(format!("{:?}", flat), "[]");
+    }
+}
@@ -0,0 +1,148 @@
+use core::fmt::{self, Debug, Display, LowerHex, UpperHex, Formatter};
+use core::ops::{Index, IndexMut};
+use super::{Idx, Slice, SliceMut, BoundsPolicy};
+
+/// Number of bytes shown on each line of a `HexDump`.
+const HEXDUMP_WIDTH: usize = 16;
+
+impl<'a, K, I, T, P> Debug for Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy,
+          T: Debug
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K, I, T, P> Debug for SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy,
+          T: Debug
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<'a, K, P> LowerHex for Slice<'a, K, usize, u8, P>
+    where K: ?Sized + Index<usize, Output = u8>,
+          P: BoundsPolicy
+{
+    /// Writes each byte as two lowercase hex digits, prefixed by `0x` with `{:#x}`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        for byte in self.iter() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, K, P> UpperHex for Slice<'a, K, usize, u8, P>
+    where K: ?Sized + Index<usize, Output = u8>,
+          P: BoundsPolicy
+{
+    /// Writes each byte as two uppercase hex digits, prefixed by `0x` with `{:#X}`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        for byte in self.iter() {
+            write!(f, "{:02X}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, K, P> Slice<'a, K, usize, u8, P>
+    where K: ?Sized + Index<usize, Output = u8>,
+          P: BoundsPolicy
+{
+    /// Returns a value which `Display`s the bytes in the style of `hexdump -C`:
+    /// 16 bytes per line, each line prefixed with its offset and followed by
+    /// an ASCII gutter in which non-printable bytes are shown as `.`.
+    pub fn hexdump(self) -> HexDump<'a, K> {
+        HexDump { slice: self.with_policy() }
+    }
+}
+
+/// A pretty hex dump of a byte slice, created by `Slice::hexdump`.
+pub struct HexDump<'a, K: 'a + ?Sized + Index<usize, Output = u8>> {
+    slice: Slice<'a, K, usize, u8>,
+}
+
+impl<'a, K> Display for HexDump<'a, K>
+    where K: ?Sized + Index<usize, Output = u8>
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.slice.is_empty() {
+            return Ok(());
+        }
+        for (line, chunk) in self.slice.chunks(HEXDUMP_WIDTH).enumerate() {
+            if line > 0 {
+                f.write_str("\n")?;
+            }
+            write!(f, "{:08x}  ", line * HEXDUMP_WIDTH)?;
+            for i in 0..HEXDUMP_WIDTH {
+                if i == HEXDUMP_WIDTH / 2 {
+                    f.write_str(" ")?;
+                }
+                match chunk.get(i) {
+                    Some(byte) => write!(f, "{:02x} ", byte)?,
+                    None => f.write_str("   ")?,
+                }
+            }
+            f.write_str(" |")?;
+            for &byte in chunk.iter() {
+                let c = if byte.is_ascii_graphic() || byte == b' ' { byte as char } else { '.' };
+                write!(f, "{}", c)?;
+            }
+            f.write_str("|")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use TakeSlice;
+
+    fn test_vec() -> VecDeque<u32> {
+        vec![1, 2, 3, 4, 5].into_iter().collect()
+    }
+
+    #[test]
+    fn debug_prints_elements() {
+        let mut v = test_vec();
+        assert_eq!(format!("{:?}", v.index_range(1..4)), "[2, 3, 4]");
+        assert_eq!(format!("{:?}", v.index_range(2..2)), "[]");
+        assert_eq!(format!("{:?}", v.index_range_mut(3..5)), "[4, 5]");
+        assert_eq!(format!("{:#?}", v.index_range(0..1)), "[\n    1,\n]");
+    }
+
+    #[test]
+    fn hex_formatting() {
+        let bytes: VecDeque<u8> = vec![0x00, 0x1f, 0xab, 0xff].into_iter().collect();
+        let slice = bytes.index_range(1..4);
+        assert_eq!(format!("{:x}", slice), "1fabff");
+        assert_eq!(format!("{:X}", slice), "1FABFF");
+        assert_eq!(format!("{:#x}", slice), "0x1fabff");
+        assert_eq!(format!("{:x}", bytes.index_range(0..0)), "");
+    }
+
+    #[test]
+    fn hexdump() {
+        let bytes: VecDeque<u8> = b"xHello, World!\n0123456789".iter().cloned().collect();
+        let dump = format!("{}", bytes.index_range(1..25).hexdump());
+        assert_eq!(dump,
+                   "00000000  48 65 6c 6c 6f 2c 20 57  6f 72 6c 64 21 0a 30 31  |Hello, World!.01|\n\
+                    00000010  32 33 34 35 36 37 38 39                           |23456789|");
+        assert_eq!(format!("{}", bytes.index_range(0..0).hexdump()), "");
+    }
+}
@@ -0,0 +1,847 @@
+use core::fmt::{self, Debug};
+use core::marker;
+use core::ops::{Index, IndexMut, Range};
+use num_traits::{Zero, One};
+use iter::remaining;
+use util::{assert_in_bounds, assert_len_fits};
+use {Idx, Iter, IterMut, TakeSlice, UniqueIndexMut};
+
+/// A trait for taking rectangular 'slices' of two-dimensional containers
+/// indexed by `(row, column)`.
+///
+/// ```
+/// use std::ops::Index;
+/// use owned_slice::TakeSlice2D;
+///
+/// struct Map {
+///     width: usize,
+///     tiles: Vec<char>,
+/// }
+///
+/// impl Index<(usize, usize)> for Map {
+///     type Output = char;
+///     fn index(&self, (row, col): (usize, usize)) -> &char {
+///         &self.tiles[row * self.width + col]
+///     }
+/// }
+///
+/// impl TakeSlice2D<char> for Map {
+///     fn dims(&self) -> (usize, usize) {
+///         (self.tiles.len() / self.width, self.width)
+///     }
+/// }
+///
+/// let map = Map { width: 3, tiles: "#.#...#.#".chars().collect() };
+/// let corner = map.slice_2d(1..3, 1..3);
+/// assert_eq!(corner[(1, 1)], '#');
+/// assert_eq!(format!("{:?}", corner), "[['.', '.'], ['.', '#']]");
+/// ```
+pub trait TakeSlice2D<T, I = usize>: Index<(I, I), Output = T>
+    where I: Idx
+{
+    /// Returns the number of rows and columns, in that order.
+    fn dims(&self) -> (I, I);
+
+    /// Slice the structure with a range of rows and a range of columns.
+    fn slice_2d(&self, rows: Range<I>, cols: Range<I>) -> Slice2D<'_, Self, I, T> {
+        let (num_rows, num_cols) = self.dims();
+        assert_in_bounds(&rows, num_rows);
+        assert_in_bounds(&cols, num_cols);
+        Slice2D {
+            list: self,
+            row_start: rows.start,
+            col_start: cols.start,
+            rows: rows.end - rows.start,
+            cols: cols.end - cols.start,
+            ty: marker::PhantomData,
+        }
+    }
+
+    /// Slice the structure with a range of rows and a range of columns,
+    /// returning a mutable reference.
+    fn slice_2d_mut(&mut self, rows: Range<I>, cols: Range<I>) -> Slice2DMut<'_, Self, I, T>
+        where Self: IndexMut<(I, I)>
+    {
+        let (num_rows, num_cols) = self.dims();
+        assert_in_bounds(&rows, num_rows);
+        assert_in_bounds(&cols, num_cols);
+        Slice2DMut {
+            list: self,
+            row_start: rows.start,
+            col_start: cols.start,
+            rows: rows.end - rows.start,
+            cols: cols.end - cols.start,
+            ty: marker::PhantomData,
+        }
+    }
+
+    /// Slice the whole structure.
+    fn slice_2d_full(&self) -> Slice2D<'_, Self, I, T> {
+        let (rows, cols) = self.dims();
+        self.slice_2d(Zero::zero()..rows, Zero::zero()..cols)
+    }
+
+    /// Slice the whole structure, returning a mutable reference.
+    fn slice_2d_full_mut(&mut self) -> Slice2DMut<'_, Self, I, T>
+        where Self: IndexMut<(I, I)>
+    {
+        let (rows, cols) = self.dims();
+        self.slice_2d_mut(Zero::zero()..rows, Zero::zero()..cols)
+    }
+}
+
+/// An immutable rectangular view of a two-dimensional container.
+pub struct Slice2D<'a, K: 'a + ?Sized + Index<(I, I), Output = T>, I: 'a + Idx, T: 'a> {
+    list: &'a K,
+    row_start: I,
+    col_start: I,
+    rows: I,
+    cols: I,
+    ty: marker::PhantomData<T>,
+}
+
+/// A mutable rectangular view of a two-dimensional container.
+pub struct Slice2DMut<'a, K: 'a + ?Sized + IndexMut<(I, I), Output = T>, I: 'a + Idx, T: 'a> {
+    list: &'a mut K,
+    row_start: I,
+    col_start: I,
+    rows: I,
+    cols: I,
+    ty: marker::PhantomData<T>,
+}
+
+/// A single row of a `Slice2D`, which can be indexed and sliced
+/// like any other one-dimensional container.
+pub struct Row<'a, K: 'a + ?Sized + Index<(I, I), Output = T>, I: 'a + Idx, T: 'a> {
+    list: &'a K,
+    row: I,
+    col_start: I,
+    len: I,
+    ty: marker::PhantomData<T>,
+}
+
+/// A single mutable row of a `Slice2DMut`.
+pub struct RowMut<'a, K: 'a + ?Sized + IndexMut<(I, I), Output = T>, I: 'a + Idx, T: 'a> {
+    // a raw pointer rather than `&'a mut K`, so that `rows_mut` can hand
+    // out every row of the same container at once. Like `SliceMut`, this
+    // keeps the rows on the thread that created them.
+    list: *mut K,
+    row: I,
+    col_start: I,
+    len: I,
+    ty: marker::PhantomData<(&'a mut K, T)>,
+}
+
+/// An iterator over the rows of a `Slice2D`, created with `Slice2D::rows`.
+pub struct Rows<'a, K: 'a + ?Sized + Index<(I, I), Output = T>, I: 'a + Idx, T: 'a> {
+    grid: Slice2D<'a, K, I, T>,
+    cur: I,
+    end: I,
+}
+
+/// An iterator over the mutable rows of a `Slice2DMut`,
+/// created with `Slice2DMut::rows_mut`.
+pub struct RowsMut<'a, K: 'a + ?Sized + UniqueIndexMut<(I, I), Output = T>, I: 'a + Idx, T: 'a> {
+    list: *mut K,
+    col_start: I,
+    cols: I,
+    cur: I,
+    end: I,
+    ty: marker::PhantomData<(&'a mut K, T)>,
+}
+
+impl<'a, K, I, T> Slice2D<'a, K, I, T>
+    where K: ?Sized + Index<(I, I), Output = T>,
+          I: Idx
+{
+    /// Returns the number of rows and columns in the view.
+    #[inline]
+    pub fn dims(&self) -> (I, I) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns `true` if the view has no rows or no columns.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.rows == Zero::zero() || self.cols == Zero::zero()
+    }
+
+    /// Returns a reference to the element at `(row, col)`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, (row, col): (I, I)) -> Option<&'a T> {
+        if row < self.rows && col < self.cols {
+            Some(&self.list[(self.row_start + row, self.col_start + col)])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the row at `row`, or `None` if it is out of bounds.
+    pub fn row(&self, row: I) -> Option<Row<'a, K, I, T>> {
+        if row < self.rows {
+            Some(Row {
+                list: self.list,
+                row: self.row_start + row,
+                col_start: self.col_start,
+                len: self.cols,
+                ty: marker::PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the rows of the view, from top to bottom.
+    pub fn rows(&self) -> Rows<'a, K, I, T> {
+        assert_len_fits(self.rows);
+        Rows {
+            grid: *self,
+            cur: Zero::zero(),
+            end: self.rows,
+        }
+    }
+
+    /// Returns the sub-grid covering `rows` and `cols` of this view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range is out of bounds.
+    pub fn subgrid(&self, rows: Range<I>, cols: Range<I>) -> Slice2D<'a, K, I, T> {
+        assert_in_bounds(&rows, self.rows);
+        assert_in_bounds(&cols, self.cols);
+        Slice2D {
+            list: self.list,
+            row_start: self.row_start + rows.start,
+            col_start: self.col_start + cols.start,
+            rows: rows.end - rows.start,
+            cols: cols.end - cols.start,
+            ty: marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, K, I, T> Slice2DMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<(I, I), Output = T>,
+          I: Idx
+{
+    /// Returns the number of rows and columns in the view.
+    #[inline]
+    pub fn dims(&self) -> (I, I) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns `true` if the view has no rows or no columns.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.rows == Zero::zero() || self.cols == Zero::zero()
+    }
+
+    /// Returns a reference to the element at `(row, col)`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, (row, col): (I, I)) -> Option<&T> {
+        if row < self.rows && col < self.cols {
+            Some(&self.list[(self.row_start + row, self.col_start + col)])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element at `(row, col)`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, (row, col): (I, I)) -> Option<&mut T> {
+        if row < self.rows && col < self.cols {
+            Some(&mut self.list[(self.row_start + row, self.col_start + col)])
+        } else {
+            None
+        }
+    }
+
+    /// Returns an immutable view of the same region.
+    pub fn as_slice_2d(&self) -> Slice2D<'_, K, I, T> {
+        Slice2D {
+            list: self.list,
+            row_start: self.row_start,
+            col_start: self.col_start,
+            rows: self.rows,
+            cols: self.cols,
+            ty: marker::PhantomData,
+        }
+    }
+
+    /// Returns the row at `row`, or `None` if it is out of bounds.
+    pub fn row(&self, row: I) -> Option<Row<'_, K, I, T>> {
+        self.as_slice_2d().row(row)
+    }
+
+    /// Returns the row at `row` mutably, or `None` if it is out of bounds.
+    pub fn row_mut(&mut self, row: I) -> Option<RowMut<'_, K, I, T>> {
+        if row < self.rows {
+            Some(RowMut {
+                list: &mut *self.list,
+                row: self.row_start + row,
+                col_start: self.col_start,
+                len: self.cols,
+                ty: marker::PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the rows of the view, from top to bottom.
+    pub fn rows(&self) -> Rows<'_, K, I, T> {
+        self.as_slice_2d().rows()
+    }
+
+    /// Returns an iterator over the mutable rows of the view, from top to bottom.
+    /// The rows don't overlap, so they can all be used at once.
+    pub fn rows_mut(&mut self) -> RowsMut<'_, K, I, T>
+        where K: UniqueIndexMut<(I, I)>
+    {
+        assert_len_fits(self.rows);
+        RowsMut {
+            list: &mut *self.list,
+            col_start: self.col_start,
+            cols: self.cols,
+            cur: self.row_start,
+            end: self.row_start + self.rows,
+            ty: marker::PhantomData,
+        }
+    }
+
+    /// Returns a mutable view of the sub-grid covering `rows` and `cols`,
+    /// borrowing from this view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range is out of bounds.
+    pub fn subgrid_mut(&mut self, rows: Range<I>, cols: Range<I>) -> Slice2DMut<'_, K, I, T> {
+        assert_in_bounds(&rows, self.rows);
+        assert_in_bounds(&cols, self.cols);
+        Slice2DMut {
+            list: self.list,
+            row_start: self.row_start + rows.start,
+            col_start: self.col_start + cols.start,
+            rows: rows.end - rows.start,
+            cols: cols.end - cols.start,
+            ty: marker::PhantomData,
+        }
+    }
+
+    /// Converts the view into a mutable view of the sub-grid
+    /// covering `rows` and `cols`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range is out of bounds.
+    pub fn into_subgrid(self, rows: Range<I>, cols: Range<I>) -> Slice2DMut<'a, K, I, T> {
+        assert_in_bounds(&rows, self.rows);
+        assert_in_bounds(&cols, self.cols);
+        Slice2DMut {
+            list: self.list,
+            row_start: self.row_start + rows.start,
+            col_start: self.col_start + cols.start,
+            rows: rows.end - rows.start,
+            cols: cols.end - cols.start,
+            ty: marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, K, I, T> Row<'a, K, I, T>
+    where K: ?Sized + Index<(I, I), Output = T>,
+          I: Idx
+{
+    /// Returns the number of elements in the row.
+    #[inline]
+    pub fn len(&self) -> I {
+        self.len
+    }
+
+    /// Returns `true` if the row has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == Zero::zero()
+    }
+
+    /// Returns a reference to the element in column `col`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, col: I) -> Option<&'a T> {
+        if col < self.len {
+            Some(&self.list[(self.row, self.col_start + col)])
+        } else {
+            None
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, Self, I, T> {
+        self.index_range_full(..).iter()
+    }
+}
+
+impl<'a, K, I, T> RowMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<(I, I), Output = T>,
+          I: Idx
+{
+    /// Returns the number of elements in the row.
+    #[inline]
+    pub fn len(&self) -> I {
+        self.len
+    }
+
+    /// Returns `true` if the row has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == Zero::zero()
+    }
+
+    /// Returns a reference to the element in column `col`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, col: I) -> Option<&T> {
+        if col < self.len {
+            Some(&self.list()[(self.row, self.col_start + col)])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element in column `col`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, col: I) -> Option<&mut T> {
+        if col < self.len {
+            let (row, col) = (self.row, self.col_start + col);
+            Some(&mut self.list_mut()[(row, col)])
+        } else {
+            None
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, Self, I, T> {
+        self.index_range_full(..).iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, Self, I, T>
+        where K: UniqueIndexMut<(I, I)>
+    {
+        self.index_range_full_mut(..).iter_mut()
+    }
+
+    #[inline]
+    fn list(&self) -> &K {
+        unsafe { &*self.list }
+    }
+
+    #[inline]
+    fn list_mut(&mut self) -> &mut K {
+        unsafe { &mut *self.list }
+    }
+}
+
+impl<'a, K, I, T> Iterator for Rows<'a, K, I, T>
+    where K: ?Sized + Index<(I, I), Output = T>,
+          I: Idx
+{
+    type Item = Row<'a, K, I, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cur == self.end {
+            return None;
+        }
+        let row = self.grid.row(self.cur);
+        self.cur = self.cur + One::one();
+        row
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        remaining(self.cur, self.end)
+    }
+}
+
+impl<'a, K, I, T> DoubleEndedIterator for Rows<'a, K, I, T>
+    where K: ?Sized + Index<(I, I), Output = T>,
+          I: Idx
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.cur == self.end {
+            return None;
+        }
+        self.end = self.end - One::one();
+        self.grid.row(self.end)
+    }
+}
+
+impl<'a, K, I, T> ExactSizeIterator for Rows<'a, K, I, T>
+    where K: ?Sized + Index<(I, I), Output = T>,
+          I: Idx
+{
+}
+
+impl<'a, K, I, T> Iterator for RowsMut<'a, K, I, T>
+    where K: ?Sized + UniqueIndexMut<(I, I), Output = T>,
+          I: Idx
+{
+    type Item = RowMut<'a, K, I, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cur == self.end {
+            return None;
+        }
+        let row = self.cur;
+        self.cur = row + One::one();
+        // each row is handed out once, and rows never overlap,
+        // so by the `UniqueIndexMut` contract they never alias
+        Some(RowMut {
+            list: self.list,
+            row,
+            col_start: self.col_start,
+            len: self.cols,
+            ty: marker::PhantomData,
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        remaining(self.cur, self.end)
+    }
+}
+
+impl<'a, K, I, T> DoubleEndedIterator for RowsMut<'a, K, I, T>
+    where K: ?Sized + UniqueIndexMut<(I, I), Output = T>,
+          I: Idx
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.cur == self.end {
+            return None;
+        }
+        self.end = self.end - One::one();
+        Some(RowMut {
+            list: self.list,
+            row: self.end,
+            col_start: self.col_start,
+            len: self.cols,
+            ty: marker::PhantomData,
+        })
+    }
+}
+
+impl<'a, K, I, T> ExactSizeIterator for RowsMut<'a, K, I, T>
+    where K: ?Sized + UniqueIndexMut<(I, I), Output = T>,
+          I: Idx
+{
+}
+
+impl<'a, K, I, T> Clone for Slice2D<'a, K, I, T>
+    where K: ?Sized + Index<(I, I), Output = T>,
+          I: Idx
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, K, I, T> Copy for Slice2D<'a, K, I, T>
+    where K: ?Sized + Index<(I, I), Output = T>,
+          I: Idx
+{
+}
+
+impl<'a, K, I, T> Clone for Row<'a, K, I, T>
+    where K: ?Sized + Index<(I, I), Output = T>,
+          I: Idx
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, K, I, T> Copy for Row<'a, K, I, T>
+    where K: ?Sized + Index<(I, I), Output = T>,
+          I: Idx
+{
+}
+
+impl<'a, K, I, T> Clone for Rows<'a, K, I, T>
+    where K: ?Sized + Index<(I, I), Output = T>,
+          I: Idx
+{
+    fn clone(&self) -> Self {
+        Rows {
+            grid: self.grid,
+            cur: self.cur,
+            end: self.end,
+        }
+    }
+}
+
+impl<'a, K, I, T> Index<(I, I)> for Slice2D<'a, K, I, T>
+    where K: ?Sized + Index<(I, I), Output = T>,
+          I: Idx
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: (I, I)) -> &T {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, self.dims()),
+        }
+    }
+}
+
+impl<'a, K, I, T> Index<(I, I)> for Slice2DMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<(I, I), Output = T>,
+          I: Idx
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: (I, I)) -> &T {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, self.dims()),
+        }
+    }
+}
+
+impl<'a, K, I, T> IndexMut<(I, I)> for Slice2DMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<(I, I), Output = T>,
+          I: Idx
+{
+    #[inline]
+    fn index_mut(&mut self, index: (I, I)) -> &mut T {
+        let dims = self.dims();
+        match self.get_mut(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, dims),
+        }
+    }
+}
+
+impl<'a, K, I, T> Index<I> for Row<'a, K, I, T>
+    where K: ?Sized + Index<(I, I), Output = T>,
+          I: Idx
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: I) -> &T {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, self.len),
+        }
+    }
+}
+
+impl<'a, K, I, T> Index<I> for RowMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<(I, I), Output = T>,
+          I: Idx
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: I) -> &T {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, self.len),
+        }
+    }
+}
+
+impl<'a, K, I, T> IndexMut<I> for RowMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<(I, I), Output = T>,
+          I: Idx
+{
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut T {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, len),
+        }
+    }
+}
+
+impl<'a, K, I, T> TakeSlice2D<T, I> for Slice2D<'a, K, I, T>
+    where K: ?Sized + Index<(I, I), Output = T>,
+          I: Idx
+{
+    fn dims(&self) -> (I, I) {
+        (self.rows, self.cols)
+    }
+}
+
+impl<'a, K, I, T> TakeSlice2D<T, I> for Slice2DMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<(I, I), Output = T>,
+          I: Idx
+{
+    fn dims(&self) -> (I, I) {
+        (self.rows, self.cols)
+    }
+}
+
+impl<'a, K, I, T> TakeSlice<T, I> for Row<'a, K, I, T>
+    where K: ?Sized + Index<(I, I), Output = T>,
+          I: Idx
+{
+    fn len(&self) -> I {
+        self.len
+    }
+}
+
+impl<'a, K, I, T> TakeSlice<T, I> for RowMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<(I, I), Output = T>,
+          I: Idx
+{
+    fn len(&self) -> I {
+        self.len
+    }
+}
+
+// every column of a row is a distinct `(row, col)` pair of the container
+unsafe impl<'a, K, I, T> UniqueIndexMut<I> for RowMut<'a, K, I, T>
+    where K: ?Sized + UniqueIndexMut<(I, I), Output = T>,
+          I: Idx
+{
+}
+
+impl<'a, K, I, T> Debug for Slice2D<'a, K, I, T>
+    where K: ?Sized + Index<(I, I), Output = T>,
+          I: Idx,
+          T: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.rows()).finish()
+    }
+}
+
+impl<'a, K, I, T> Debug for Slice2DMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<(I, I), Output = T>,
+          I: Idx,
+          T: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.rows()).finish()
+    }
+}
+
+impl<'a, K, I, T> Debug for Row<'a, K, I, T>
+    where K: ?Sized + Index<(I, I), Output = T>,
+          I: Idx,
+          T: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K, I, T> Debug for RowMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<(I, I), Output = T>,
+          I: Idx,
+          T: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::ops::{Index, IndexMut};
+    use {TakeSlice, TakeSlice2D, UniqueIndexMut};
+
+    /// A row-major grid for testing. The cells are in a `VecDeque` rather
+    /// than a `Vec` so that the grid can be `UniqueIndexMut`.
+    struct Grid {
+        cols: usize,
+        cells: VecDeque<usize>,
+    }
+
+    impl Index<(usize, usize)> for Grid {
+        type Output = usize;
+        fn index(&self, (row, col): (usize, usize)) -> &usize {
+            assert!(col < self.cols);
+            &self.cells[row * self.cols + col]
+        }
+    }
+
+    impl IndexMut<(usize, usize)> for Grid {
+        fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut usize {
+            assert!(col < self.cols);
+            &mut self.cells[row * self.cols + col]
+        }
+    }
+
+    impl TakeSlice2D<usize> for Grid {
+        fn dims(&self) -> (usize, usize) {
+            (self.cells.len() / self.cols, self.cols)
+        }
+    }
+
+    unsafe impl UniqueIndexMut<(usize, usize)> for Grid {}
+
+    fn test_grid() -> Grid {
+        Grid { cols: 4, cells: (0..12).collect() }
+    }
+
+    #[test]
+    fn slice_2d() {
+        let grid = test_grid();
+        let s = grid.slice_2d(1..3, 1..4);
+        assert_eq!(s.dims(), (2, 3));
+        assert_eq!(format!("{:?}", s), "[[5, 6, 7], [9, 10, 11]]");
+        assert_eq!(s[(1, 0)], 9);
+        assert_eq!(s.get((2, 0)), None);
+        assert_eq!(s.row(0).unwrap().index_range(1..3), [6, 7]);
+        assert_eq!(s.rows().next_back().unwrap().iter().sum::<usize>(), 30);
+        assert_eq!(format!("{:?}", s.subgrid(0..2, 2..3)), "[[7], [11]]");
+        assert_eq!(format!("{:?}", s.slice_2d(1..2, 0..2)), "[[9, 10]]");
+        assert!(s.subgrid(1..1, 0..3).is_empty());
+    }
+
+    #[test]
+    fn slice_2d_mut() {
+        let mut grid = test_grid();
+        {
+            let mut s = grid.slice_2d_mut(0..2, 2..4);
+            s[(0, 0)] = 100;
+            for x in s.row_mut(1).unwrap().iter_mut() {
+                *x = 0;
+            }
+            s.subgrid_mut(0..1, 1..2)[(0, 0)] = 200;
+            s.into_subgrid(1..2, 0..1).row_mut(0).unwrap().index_range_full_mut(..)[0] += 1;
+        }
+        assert_eq!(grid.cells, [0, 1, 100, 200, 4, 5, 1, 0, 8, 9, 10, 11]);
+    }
+
+    #[test]
+    fn rows_mut() {
+        let mut grid = test_grid();
+        {
+            let mut s = grid.slice_2d_mut(1..3, 0..3);
+            let mut rows = s.rows_mut();
+            assert_eq!(rows.len(), 2);
+            let mut top = rows.next().unwrap();
+            let mut bottom = rows.next_back().unwrap();
+            assert!(rows.next().is_none());
+            for (a, b) in top.iter_mut().zip(bottom.iter_mut()) {
+                ::std::mem::swap(a, b);
+            }
+            top[0] += bottom[0];
+        }
+        assert_eq!(grid.cells, [0, 1, 2, 3, 12, 9, 10, 7, 4, 5, 6, 11]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn slice_2d_out_of_bounds() {
+        test_grid().slice_2d(0..4, 0..1);
+    }
+}
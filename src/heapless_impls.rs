@@ -0,0 +1,94 @@
+// `heapless::Vec` dereferences to `[T]` without implementing `Index` itself,
+// so it is sliced through the `[T]` impl (`v[..].index_range(..)`),
+// or through an `IndexedSlice` to split or iterate it mutably.
+// `heapless::Deque` has neither, so it is wrapped in `IndexedDeque`.
+
+use core::ops::{Index, IndexMut};
+use heapless::Deque;
+use super::{TakeSlice, UniqueIndexMut, IndexedSlice};
+
+/// Indexes into a `heapless::Deque` from front to back,
+/// so that it can be sliced with `TakeSlice`.
+///
+/// The deque stores its elements inline, so they are reached through an
+/// `IndexedSlice` for each of its two halves, taken when it is wrapped.
+pub struct IndexedDeque<'d, T: 'd> {
+    front: IndexedSlice<'d, T>,
+    back: IndexedSlice<'d, T>,
+}
+
+impl<'d, T> IndexedDeque<'d, T> {
+    pub fn new<const N: usize>(deque: &'d mut Deque<T, N>) -> Self {
+        let (front, back) = deque.as_mut_slices();
+        IndexedDeque {
+            front: IndexedSlice::new(front),
+            back: IndexedSlice::new(back),
+        }
+    }
+}
+
+impl<'d, T> Index<usize> for IndexedDeque<'d, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        let split = self.front.len();
+        if index < split {
+            &self.front[index]
+        } else {
+            &self.back[index - split]
+        }
+    }
+}
+
+impl<'d, T> IndexMut<usize> for IndexedDeque<'d, T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let split = self.front.len();
+        if index < split {
+            &mut self.front[index]
+        } else {
+            &mut self.back[index - split]
+        }
+    }
+}
+
+impl<'d, T> TakeSlice<T, usize> for IndexedDeque<'d, T> {
+    fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+}
+
+// both halves are `UniqueIndexMut`, and don't overlap each other
+unsafe impl<'d, T> UniqueIndexMut<usize> for IndexedDeque<'d, T> {}
+
+#[cfg(test)]
+mod tests {
+    use heapless::{Deque, Vec};
+    use super::IndexedDeque;
+    use {TakeSlice, IndexedSlice};
+
+    #[test]
+    fn vec() {
+        let mut v: Vec<u32, 8> = (0..5).collect();
+        assert_eq!(v[..].index_range(1..4), [1, 2, 3]);
+        IndexedSlice::new(&mut v).index_range_mut(0..5).reverse();
+        assert_eq!(&v[..], &[4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn deque() {
+        let mut d: Deque<u32, 4> = Deque::new();
+        for i in 0..4 {
+            d.push_back(i).unwrap();
+        }
+        // wrap the contents around the end of the buffer
+        d.pop_front();
+        d.pop_front();
+        d.push_back(4).unwrap();
+        d.push_back(5).unwrap();
+        let mut d = IndexedDeque::new(&mut d);
+        assert_eq!(d.index_range(0..4), [2, 3, 4, 5]);
+        d.index_range_mut(1..4).rotate_left(1);
+        assert_eq!(d.index_range_full(..), [2, 4, 5, 3]);
+        assert!(d.try_index_range(2..5).is_err());
+    }
+}
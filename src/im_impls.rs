@@ -0,0 +1,36 @@
+use im::Vector;
+use super::TakeSlice;
+
+// `Vector` is deliberately not `UniqueIndexMut`: `index_mut` copies shared
+// chunks on write, so it gives no guarantee about references handed out earlier.
+impl<A: Clone> TakeSlice<A, usize> for Vector<A> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use im::Vector;
+    use TakeSlice;
+
+    #[test]
+    fn take_slice() {
+        let v: Vector<u32> = (0..100).collect();
+        let window = v.index_range(40..45);
+        assert_eq!(window, [40, 41, 42, 43, 44]);
+        assert_eq!(window.last(), Some(&44));
+        assert_eq!(v.index_range(10..90).windows(3).count(), 78);
+    }
+
+    #[test]
+    fn structural_sharing() {
+        let original: Vector<u32> = (0..100).collect();
+        let mut copy = original.clone();
+        copy.index_range_mut(50..60).fill(0);
+        assert_eq!(copy.index_range(48..52), [48, 49, 0, 0]);
+        // the original is untouched by writes through the copy's slice
+        assert_eq!(original.index_range(48..52), [48, 49, 50, 51]);
+        assert_eq!(original.index_range(0..50), copy.index_range(0..50));
+    }
+}
@@ -0,0 +1,122 @@
+use core::ops::{Index, IndexMut};
+use core::ptr::NonNull;
+use core::marker;
+use core::slice;
+use super::{TakeSlice, UniqueIndexMut, SliceMut, BoundsPolicy};
+
+/// Indexes into a `[T]` through a raw pointer, so that it can be split and
+/// iterated mutably with `TakeSlice`.
+///
+/// `[T]`, arrays and `Vec` implement `TakeSlice` themselves, but they can't
+/// be `UniqueIndexMut`: borrowing an array borrows every element in it, and
+/// `Vec` indexes through a slice of its whole buffer, so a view using one
+/// element would invalidate references to the others. `IndexedSlice` only
+/// borrows the slice once, when it is created.
+///
+/// ```
+/// use owned_slice::{IndexedSlice, TakeSlice};
+///
+/// let mut v = vec![3, 1, 2, 0];
+/// let mut indexed = IndexedSlice::new(&mut v);
+/// let (mut a, mut b) = indexed.index_range_full_mut(..).split_at_mut(2);
+/// a.sort();
+/// b.sort();
+/// assert_eq!(v, [1, 3, 0, 2]);
+/// ```
+pub struct IndexedSlice<'s, T: 's> {
+    ptr: NonNull<T>,
+    len: usize,
+    ty: marker::PhantomData<&'s mut [T]>,
+}
+
+// `IndexedSlice` stands in for the `&'s mut [T]` it was created from
+unsafe impl<'s, T: Send> Send for IndexedSlice<'s, T> {}
+
+unsafe impl<'s, T: Sync> Sync for IndexedSlice<'s, T> {}
+
+impl<'s, T> IndexedSlice<'s, T> {
+    pub fn new(slice: &'s mut [T]) -> Self {
+        IndexedSlice {
+            // `as_mut_ptr` is never null, and dangling but aligned if the slice is empty
+            ptr: unsafe { NonNull::new_unchecked(slice.as_mut_ptr()) },
+            len: slice.len(),
+            ty: marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, 's, T, P> SliceMut<'a, IndexedSlice<'s, T>, usize, T, P>
+    where P: BoundsPolicy
+{
+    /// Converts the view into the part of the underlying `[T]` which it covers.
+    pub fn into_mut_slice(self) -> &'a mut [T] {
+        // read the pointer without borrowing the `IndexedSlice`, which
+        // other views of it may be using. The view owns `start..start + len`.
+        unsafe {
+            let ptr = (*self.list).ptr.as_ptr();
+            slice::from_raw_parts_mut(ptr.add(self.start), self.len)
+        }
+    }
+}
+
+impl<'s, T> From<&'s mut [T]> for IndexedSlice<'s, T> {
+    fn from(slice: &'s mut [T]) -> Self {
+        IndexedSlice::new(slice)
+    }
+}
+
+impl<'s, T> Index<usize> for IndexedSlice<'s, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        if index >= self.len {
+            panic!("Index out of bounds: {:?} >= {:?}", index, self.len);
+        }
+        unsafe { &*self.ptr.as_ptr().add(index) }
+    }
+}
+
+impl<'s, T> IndexMut<usize> for IndexedSlice<'s, T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        if index >= self.len {
+            panic!("Index out of bounds: {:?} >= {:?}", index, self.len);
+        }
+        unsafe { &mut *self.ptr.as_ptr().add(index) }
+    }
+}
+
+impl<'s, T> TakeSlice<T, usize> for IndexedSlice<'s, T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+// the elements are only reached by offsetting `ptr`,
+// which borrowing the `IndexedSlice` itself doesn't cover
+unsafe impl<'s, T> UniqueIndexMut<usize> for IndexedSlice<'s, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::IndexedSlice;
+    use TakeSlice;
+
+    #[test]
+    fn split_and_iterate() {
+        let mut array = [5, 4, 3, 2, 1, 0];
+        {
+            let mut indexed = IndexedSlice::new(&mut array);
+            let refs: Vec<_> = indexed.index_range_mut(1..5).iter_mut().collect();
+            for x in refs {
+                *x *= 10;
+            }
+            let (mut a, mut b) = indexed.index_range_full_mut(..).split_at_mut(3);
+            a[0] += b[0];
+            b.sort();
+            assert_eq!(indexed.index_range(2..4), [30, 0]);
+            assert!(indexed.try_index_range(4..7).is_err());
+        }
+        assert_eq!(array, [25, 40, 30, 0, 10, 20]);
+    }
+}
@@ -0,0 +1,34 @@
+use indexmap::IndexMap;
+use super::TakeSlice;
+
+// `IndexMap` is deliberately not `UniqueIndexMut`: `index_mut` goes through
+// a slice of all the entries, which would invalidate references to the others.
+
+/// Slices an `IndexMap`'s values by their insertion position,
+/// using its positional `Index<usize>` impl (equivalent to `get_index`).
+impl<K, V, S> TakeSlice<V, usize> for IndexMap<K, V, S> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+    use TakeSlice;
+
+    #[test]
+    fn positional_slicing() {
+        let mut map = IndexMap::new();
+        map.insert("c", 3);
+        map.insert("a", 1);
+        map.insert("d", 4);
+        map.insert("b", 2);
+        assert_eq!(map.index_range(1..3), [1, 4]);
+        map.index_range_mut(1..3).map_in_place(|x| x * 10);
+        // the keys stay in place, only the values change
+        assert_eq!(map.get_index(1), Some((&"a", &10)));
+        assert_eq!(map["d"], 40);
+        assert!(map.try_index_range(2..5).is_err());
+    }
+}
@@ -0,0 +1,121 @@
+use core::cmp;
+use std::collections::VecDeque;
+use std::io::{self, Read, BufRead, Write};
+use core::ops::{Index, IndexMut};
+use super::{Slice, SliceMut, BoundsPolicy};
+use util::deque_segment;
+
+impl<'a, K, P> Read for Slice<'a, K, usize, u8, P>
+    where K: ?Sized + Index<usize, Output = u8>,
+          P: BoundsPolicy
+{
+    /// Reads bytes from the front of the slice, which then shrinks
+    /// to cover only the unread bytes, just like `&[u8]`.
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = cmp::min(buf.len(), self.len);
+        for (i, byte) in buf[..n].iter_mut().enumerate() {
+            *byte = self.list[self.start + i];
+        }
+        self.start += n;
+        self.len -= n;
+        Ok(n)
+    }
+}
+
+impl<'a, P: BoundsPolicy> BufRead for Slice<'a, VecDeque<u8>, usize, u8, P> {
+    /// Returns the bytes up to the end of the `VecDeque`'s current
+    /// contiguous segment, so may return less than the whole slice.
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(deque_segment(self.list, self.start, self.len))
+    }
+
+    fn consume(&mut self, amt: usize) {
+        let amt = cmp::min(amt, self.len);
+        self.start += amt;
+        self.len -= amt;
+    }
+}
+
+impl<'a, K, P> Write for SliceMut<'a, K, usize, u8, P>
+    where K: ?Sized + IndexMut<usize, Output = u8>,
+          P: BoundsPolicy
+{
+    /// Writes bytes to the front of the slice, which then shrinks to cover
+    /// only the unwritten bytes, just like `&mut [u8]`.
+    ///
+    /// Returns `Ok(0)` once the slice is full, so `write_all` fails
+    /// with `ErrorKind::WriteZero`.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = cmp::min(buf.len(), self.len);
+        let start = self.start;
+        let list = self.list_mut();
+        for (i, &byte) in buf[..n].iter().enumerate() {
+            list[start + i] = byte;
+        }
+        self.start += n;
+        self.len -= n;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::io::{Read, BufRead, Write, ErrorKind};
+    use TakeSlice;
+
+    // a ring buffer whose contents wrap around the end of its storage
+    fn test_vec() -> VecDeque<u8> {
+        let mut v: VecDeque<u8> = VecDeque::with_capacity(16);
+        v.extend(b"xxxxxxxxxx");
+        v.drain(..9);
+        v.extend(b"hello\nworld");
+        v.pop_front();
+        v
+    }
+
+    #[test]
+    fn read() {
+        let v = test_vec();
+        let mut slice = v.index_range(1..9);
+        let mut buf = [0; 3];
+        assert_eq!(slice.read(&mut buf).unwrap(), 3);
+        assert_eq!(&buf, b"ell");
+        let mut rest = Vec::new();
+        slice.read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, b"o\nwor");
+        assert!(slice.is_empty());
+        assert_eq!(slice.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn buf_read() {
+        let v = test_vec();
+        assert!(!v.as_slices().1.is_empty());
+        let lines: Vec<String> = v.index_range(0..11).lines().map(Result::unwrap).collect();
+        assert_eq!(lines, vec!["hello", "world"]);
+        let mut slice = v.index_range(3..8);
+        let mut line = String::new();
+        slice.read_line(&mut line).unwrap();
+        assert_eq!(line, "lo\n");
+        assert_eq!(slice.fill_buf().unwrap(), b"wo");
+    }
+
+    #[test]
+    fn write() {
+        let mut v = test_vec();
+        {
+            let mut slice = v.index_range_mut(6..11);
+            write!(slice, "{}", 42).unwrap();
+            assert_eq!(slice.len(), 3);
+            assert_eq!(slice.write(b"abcd").unwrap(), 3);
+            assert_eq!(slice.write(b"e").unwrap(), 0);
+            assert_eq!(slice.write_all(b"e").unwrap_err().kind(), ErrorKind::WriteZero);
+        }
+        assert_eq!(v.iter().cloned().collect::<Vec<_>>(), b"hello\n42abc");
+    }
+}
@@ -1,12 +1,13 @@
-use std::ops::{Index, IndexMut};
-use std::fmt::Debug;
-use std::marker;
-use num_traits::One;
-use super::{Idx, Slice, SliceMut};
+use core::ops::{Index, IndexMut};
+use core::ptr::NonNull;
+use core::marker;
+use super::{Step, Slice, SliceMut, UniqueIndexMut, BoundsPolicy};
+use util::{index_unique, assert_len_fits, offset, span};
 
-impl<'a, K, I, T> IntoIterator for Slice<'a, K, I, T>
-    where K: Index<I, Output = T>,
-          I: Idx
+impl<'a, K, I, T, P> IntoIterator for Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Step,
+          P: BoundsPolicy
 {
     type Item = &'a T;
     type IntoIter = Iter<'a, K, I, T>;
@@ -15,8 +16,20 @@ impl<'a, K, I, T> IntoIterator for Slice<'a, K, I, T>
     }
 }
 
+impl<'a, K, I, T, P> IntoIterator for &Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Step,
+          P: BoundsPolicy
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, K, I, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        Iter::new(*self)
+    }
+}
+
 /// The iterator for an immutable slice.
-pub struct Iter<'a, K: 'a + Index<I, Output = T>, I: 'a + Idx, T: 'a> {
+pub struct Iter<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Step, T: 'a> {
     list: &'a K,
     cur: I,
     end: I,
@@ -24,22 +37,23 @@ pub struct Iter<'a, K: 'a + Index<I, Output = T>, I: 'a + Idx, T: 'a> {
 }
 
 impl<'a, K, I, T> Iter<'a, K, I, T>
-    where K: Index<I, Output = T>,
-          I: Idx + Debug
+    where K: ?Sized + Index<I, Output = T>,
+          I: Step
 {
-    pub fn new(slice: Slice<'a, K, I, T>) -> Self {
+    pub fn new<P: BoundsPolicy>(slice: Slice<'a, K, I, T, P>) -> Self {
+        assert_len_fits(slice.len);
         Iter {
             list: slice.list,
             cur: slice.start,
-            end: slice.start + slice.len,
+            end: offset(slice.start, slice.len),
             ty: marker::PhantomData,
         }
     }
 }
 
 impl<'a, K, I, T> Iterator for Iter<'a, K, I, T>
-    where K: Index<I, Output = T>,
-          I: Idx
+    where K: ?Sized + Index<I, Output = T>,
+          I: Step
 {
     type Item = &'a T;
 
@@ -48,16 +62,66 @@ impl<'a, K, I, T> Iterator for Iter<'a, K, I, T>
             x if x == self.end => None,
             _ => {
                 let item = &self.list[self.cur];
-                self.cur = self.cur + One::one();
+                self.cur = self.cur.successor();
                 Some(item)
             }
         }
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        remaining(self.cur, self.end)
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+        where F: FnMut(B, Self::Item) -> B
+    {
+        let mut acc = init;
+        let mut i = self.cur;
+        while i != self.end {
+            acc = f(acc, &self.list[i]);
+            i = i.successor();
+        }
+        acc
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.cur = advance(self.cur, self.end, n);
+        self.next()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, K, I, T> DoubleEndedIterator for Iter<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Step
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.end {
+            x if x == self.cur => None,
+            _ => {
+                self.end = before(self.cur, self.end);
+                Some(&self.list[self.end])
+            }
+        }
+    }
 }
 
-impl<'a, K, I, T> IntoIterator for SliceMut<'a, K, I, T>
-    where K: IndexMut<I, Output = T>,
-          I: Idx
+impl<'a, K, I, T, P> IntoIterator for SliceMut<'a, K, I, T, P>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Step,
+          P: BoundsPolicy
 {
     type Item = &'a mut T;
     type IntoIter = IterMut<'a, K, I, T>;
@@ -66,31 +130,72 @@ impl<'a, K, I, T> IntoIterator for SliceMut<'a, K, I, T>
     }
 }
 
+impl<'a, 'b, K, I, T, P> IntoIterator for &'b SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Step,
+          P: BoundsPolicy
+{
+    type Item = &'b T;
+    type IntoIter = Iter<'b, K, I, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, 'b, K, I, T, P> IntoIterator for &'b mut SliceMut<'a, K, I, T, P>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Step,
+          P: BoundsPolicy
+{
+    type Item = &'b mut T;
+    type IntoIter = IterMut<'b, K, I, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut::new(self.reborrow())
+    }
+}
+
 /// The iterator for a mutable slice.
-pub struct IterMut<'a, K: 'a + IndexMut<I, Output = T>, I: 'a + Idx, T: 'a> {
-    list: &'a mut K,
+///
+/// Each call to `next` hands out a reference which outlives the iterator's
+/// own borrow of the container, so the container must promise through
+/// `UniqueIndexMut` that distinct indices never alias.
+pub struct IterMut<'a, K: 'a + ?Sized + UniqueIndexMut<I, Output = T>, I: 'a + Step, T: 'a> {
+    // like `SliceMut`, not `Send` or `Sync`, as other views of the
+    // container may be borrowing it on this thread
+    list: NonNull<K>,
     cur: I,
     end: I,
-    ty: marker::PhantomData<T>,
+    ty: marker::PhantomData<(&'a mut K, T)>,
 }
 
 impl<'a, K, I, T> IterMut<'a, K, I, T>
-    where K: IndexMut<I, Output = T>,
-          I: Idx
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Step
 {
-    pub fn new(slice: SliceMut<'a, K, I, T>) -> Self {
+    pub fn new<P: BoundsPolicy>(slice: SliceMut<'a, K, I, T, P>) -> Self {
+        assert_len_fits(slice.len);
         IterMut {
-            list: slice.list,
+            // `SliceMut` is only ever created from a `&'a mut K`
+            list: unsafe { NonNull::new_unchecked(slice.list) },
             cur: slice.start,
-            end: slice.start + slice.len,
+            end: offset(slice.start, slice.len),
             ty: marker::PhantomData,
         }
     }
+
+    /// Fetches the element at `index` for the full lifetime `'a`.
+    ///
+    /// The iterator never yields the same index twice, so by the
+    /// `UniqueIndexMut` contract the returned references never overlap.
+    #[inline]
+    fn get(&mut self, index: I) -> &'a mut T {
+        unsafe { index_unique(self.list.as_ptr(), index) }
+    }
 }
 
 impl<'a, K, I, T> Iterator for IterMut<'a, K, I, T>
-    where K: IndexMut<I, Output = T>,
-          I: Idx
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Step
 {
     type Item = &'a mut T;
 
@@ -98,13 +203,204 @@ impl<'a, K, I, T> Iterator for IterMut<'a, K, I, T>
         match self.cur {
             x if x == self.end => None,
             _ => {
-                let item = &mut self.list[self.cur];
-                // let's skip borrowck here just like `std` does :D
-                // PS: I hope its safe!
-                let item = unsafe { &mut *(item as *mut _) };
-                self.cur = self.cur + One::one();
-                Some(item)
+                let cur = self.cur;
+                self.cur = cur.successor();
+                Some(self.get(cur))
             }
         }
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        remaining(self.cur, self.end)
+    }
+
+    #[inline]
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+        where F: FnMut(B, Self::Item) -> B
+    {
+        let mut acc = init;
+        let mut i = self.cur;
+        while i != self.end {
+            acc = f(acc, self.get(i));
+            i = i.successor();
+        }
+        acc
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.cur = advance(self.cur, self.end, n);
+        self.next()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, K, I, T> DoubleEndedIterator for IterMut<'a, K, I, T>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Step
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.end {
+            x if x == self.cur => None,
+            _ => {
+                self.end = before(self.cur, self.end);
+                let end = self.end;
+                Some(self.get(end))
+            }
+        }
+    }
+}
+
+impl<'a, K, I, T> ExactSizeIterator for Iter<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Step
+{
+}
+
+impl<'a, K, I, T> ExactSizeIterator for IterMut<'a, K, I, T>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Step
+{
+}
+
+/// The size hint for the indices `cur..end`.
+///
+/// It is only exact if their number fits in a `usize`, so iterators which
+/// are `ExactSizeIterator` check that with `assert_len_fits` when created.
+#[inline]
+pub fn remaining<I: Step>(cur: I, end: I) -> (usize, Option<usize>) {
+    match Step::distance(cur, end) {
+        Some(n) => (n, Some(n)),
+        None => (usize::MAX, None),
+    }
+}
+
+/// Moves `cur` forward by `n`, without going past `end`.
+#[inline]
+fn advance<I: Step>(cur: I, end: I, n: usize) -> I {
+    match Step::distance(cur, end) {
+        Some(left) if n < left => cur.forward(n),
+        _ => end,
+    }
+}
+
+/// The index before `end`, which must be greater than `cur`.
+#[inline]
+fn before<I: Step>(cur: I, end: I) -> I {
+    match Step::distance(cur, end) {
+        Some(left) => cur.forward(left - 1),
+        None => panic!("Index out of range: {:?}..{:?}", cur, end),
+    }
+}
+
+/// An iterator over an immutable slice which also yields each element's index.
+pub struct IterIndexed<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Step, T: 'a> {
+    iter: Iter<'a, K, I, T>,
+    start: I,
+}
+
+impl<'a, K, I, T> IterIndexed<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Step
+{
+    pub fn new<P: BoundsPolicy>(slice: Slice<'a, K, I, T, P>) -> Self {
+        IterIndexed {
+            start: slice.start,
+            iter: Iter::new(slice),
+        }
+    }
+}
+
+impl<'a, K, I, T> Iterator for IterIndexed<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Step
+{
+    type Item = (I, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = span(self.start, self.iter.cur);
+        self.iter.next().map(|item| (index, item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K, I, T> DoubleEndedIterator for IterIndexed<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Step
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let start = self.start;
+        self.iter.next_back().map(|item| (span(start, self.iter.end), item))
+    }
+}
+
+impl<'a, K, I, T> ExactSizeIterator for IterIndexed<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Step
+{
+}
+
+/// An iterator over a mutable slice which also yields each element's index.
+pub struct IterIndexedMut<'a, K: 'a + ?Sized + UniqueIndexMut<I, Output = T>, I: 'a + Step, T: 'a> {
+    iter: IterMut<'a, K, I, T>,
+    start: I,
+}
+
+impl<'a, K, I, T> IterIndexedMut<'a, K, I, T>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Step
+{
+    pub fn new<P: BoundsPolicy>(slice: SliceMut<'a, K, I, T, P>) -> Self {
+        IterIndexedMut {
+            start: slice.start,
+            iter: IterMut::new(slice),
+        }
+    }
+}
+
+impl<'a, K, I, T> Iterator for IterIndexedMut<'a, K, I, T>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Step
+{
+    type Item = (I, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = span(self.start, self.iter.cur);
+        self.iter.next().map(|item| (index, item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K, I, T> DoubleEndedIterator for IterIndexedMut<'a, K, I, T>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Step
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let start = self.start;
+        self.iter.next_back().map(|item| (span(start, self.iter.end), item))
+    }
+}
+
+impl<'a, K, I, T> ExactSizeIterator for IterIndexedMut<'a, K, I, T>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Step
+{
 }
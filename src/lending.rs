@@ -0,0 +1,118 @@
+use core::ops::IndexMut;
+use num_traits::{Zero, One};
+use {BoundsPolicy, Idx, SliceMut};
+use util::to_usize;
+
+/// An iterator whose items borrow from the iterator itself,
+/// so each item must be dropped before the next call to `next`.
+///
+/// This allows overlapping mutable views, which a normal `Iterator`
+/// cannot hand out. Use it with `while let`:
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use owned_slice::{LendingIterator, TakeSlice};
+///
+/// let mut signal: VecDeque<i32> = vec![0, 3, 0, 3, 0].into_iter().collect();
+/// let mut windows = signal.index_range_full_mut(..).windows_mut(3);
+/// while let Some(mut w) = windows.next() {
+///     // a causal smoothing pass, which sees the already smoothed values
+///     w[1] = (w[0] + w[1] + w[2]) / 3;
+/// }
+/// assert_eq!(signal, [0, 1, 1, 1, 0]);
+/// ```
+pub trait LendingIterator {
+    /// The type of the elements being iterated over.
+    type Item<'b> where Self: 'b;
+
+    /// Advances the iterator and returns the next value.
+    fn next(&mut self) -> Option<Self::Item<'_>>;
+
+    /// Returns the bounds on the remaining length of the iterator.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+/// A lending iterator over overlapping mutable sub-slices of length `size`.
+///
+/// Returned by `SliceMut::windows_mut`.
+pub struct WindowsMut<'a, K: 'a + ?Sized + IndexMut<I, Output = T>, I: 'a + Idx, T: 'a> {
+    slice: SliceMut<'a, K, I, T>,
+    size: I,
+    cur: I,
+}
+
+impl<'a, K, I, T, P> SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Returns a lending iterator over all contiguous windows of length `size`.
+    /// The windows overlap, so each one must be dropped before asking for the
+    /// next. If the slice is shorter than `size`, the iterator returns no values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn windows_mut(self, size: I) -> WindowsMut<'a, K, I, T> {
+        assert!(size != Zero::zero(), "window size must be non-zero");
+        WindowsMut {
+            slice: self.with_policy(),
+            size,
+            cur: Zero::zero(),
+        }
+    }
+}
+
+impl<'a, K, I, T> LendingIterator for WindowsMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx
+{
+    type Item<'b> = SliceMut<'b, K, I, T> where Self: 'b;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        if self.slice.len() < self.size || self.cur > self.slice.len() - self.size {
+            return None;
+        }
+        let start = self.cur;
+        self.cur = start + One::one();
+        Some(self.slice.subslice_mut(start..start + self.size))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.slice.len() < self.size || self.cur > self.slice.len() - self.size {
+            (0, Some(0))
+        } else {
+            let n = to_usize(self.slice.len() - self.size - self.cur) + 1;
+            (n, Some(n))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use {LendingIterator, TakeSlice};
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..10).collect()
+    }
+
+    #[test]
+    fn windows_mut() {
+        let mut v = test_vec();
+        {
+            let mut windows = v.index_range_mut(2..7).windows_mut(2);
+            assert_eq!(windows.size_hint(), (4, Some(4)));
+            while let Some(mut w) = windows.next() {
+                // prefix sums, carried along by the overlap
+                w[1] += w[0];
+            }
+            assert_eq!(windows.size_hint(), (0, Some(0)));
+        }
+        assert_eq!(v.index_range(2..7), [2, 5, 9, 14, 20]);
+        assert!(v.index_range_to_mut(..3).windows_mut(4).next().is_none());
+    }
+}
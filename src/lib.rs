@@ -85,176 +85,882 @@
 //!
 //! ```
 //!
+//! # Contiguous containers
+//!
+//! Containers which only dereference to `[T]` without implementing `Index`,
+//! like `arrayvec::ArrayVec` or `heapless::Vec`, are sliced through the
+//! `[T]` impl, e.g. `av[..].index_range(1..3)`. Swapping, reversing,
+//! rotating and sorting work through it too, as they only need `IndexMut`,
+//! but to split or iterate them mutably, wrap them in an `IndexedSlice` first.
+//!
+//! # `no_std`
+//!
+//! The crate is `no_std` when the default `std` feature is disabled.
+//! The `alloc` feature brings back the `VecDeque` impls and conversions
+//! into `Vec`, while `std` additionally enables the `std::io` impls.
+//!
 
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "nightly", feature(core_intrinsics))]
+
+#[cfg(feature = "std")]
+extern crate core;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "arrayvec")]
+extern crate arrayvec;
+#[cfg(feature = "bitvec")]
+extern crate bitvec;
+#[cfg(feature = "bytes")]
+extern crate bytes;
+#[cfg(feature = "heapless")]
+extern crate heapless;
+#[cfg(feature = "im")]
+extern crate im;
+#[cfg(feature = "indexmap")]
+extern crate indexmap;
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
+#[cfg(feature = "ndarray")]
+extern crate ndarray;
 extern crate num_traits;
+#[cfg(feature = "derive")]
+extern crate owned_slice_derive;
+// lets the derive's `::owned_slice` paths resolve in our own tests
+#[cfg(all(test, feature = "derive"))]
+extern crate self as owned_slice;
+#[cfg(feature = "rand")]
+extern crate rand;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "slab")]
+extern crate slab;
+#[cfg(feature = "smallvec")]
+extern crate smallvec;
+#[cfg(feature = "tinyvec")]
+extern crate tinyvec;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_test;
 
+#[macro_use]
+mod macros;
+#[cfg(feature = "arrayvec")]
+mod arrayvec_impls;
+#[cfg(feature = "bitvec")]
+mod bitvec_impls;
+#[cfg(feature = "bytes")]
+mod bytes_impls;
+mod cell;
+mod chain;
+mod chunks;
+mod cmp;
+mod convert;
+#[cfg(feature = "alloc")]
+mod cow;
+mod cursor;
+mod dyn_slice;
+mod error;
+#[cfg(feature = "alloc")]
+mod flat;
+mod fmt;
+mod grid;
+#[cfg(feature = "heapless")]
+mod heapless_impls;
+#[cfg(feature = "im")]
+mod im_impls;
+mod indexed;
+#[cfg(feature = "indexmap")]
+mod indexmap_impls;
+#[cfg(feature = "std")]
+mod io;
 mod iter;
+mod lending;
+mod map;
+mod masked;
+#[cfg(feature = "math")]
+mod math;
+mod mutate;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impls;
+#[cfg(feature = "ndarray")]
+mod ndarray_impls;
+mod owned;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod permuted;
+mod policy;
+#[cfg(feature = "rand")]
+mod random;
+mod reversed;
+mod search;
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(feature = "alloc")]
+mod shared;
+#[cfg(feature = "slab")]
+mod slab_impls;
+#[cfg(feature = "smallvec")]
+mod smallvec_impls;
+#[cfg(feature = "tinyvec")]
+mod tinyvec_impls;
+mod sort;
+mod split;
+mod step;
+mod strided;
+#[cfg(feature = "std")]
+mod sync;
 mod util;
+mod wrapping;
+mod zip;
+
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::{Add, Sub, Range, RangeTo, RangeFrom, RangeFull, RangeInclusive, RangeToInclusive,
+               RangeBounds, Index, IndexMut};
+use core::cmp::{Eq, Ord};
+use core::fmt::Debug;
+use core::marker;
+use num_traits::{Zero, One, NumCast, Signed};
 
-use std::collections::VecDeque;
-use std::ops::{Add, Sub, Range, RangeTo, RangeFrom, Index, IndexMut};
-use std::cmp::{Eq, Ord};
-use std::fmt::Debug;
-use std::marker;
-use num_traits::{Zero, One};
+#[cfg(feature = "bytes")]
+pub use bytes_impls::{ByteChunks, ByteChunksMut};
+pub use cell::CellSlice;
+pub use chain::Chain;
+pub use chunks::{Windows, Chunks, ChunksExact, ChunksMut, RChunks, RChunksExact, Pairwise, Triplewise};
+#[cfg(feature = "alloc")]
+pub use cow::CowSlice;
+pub use cursor::{Cursor, CursorMut};
+pub use dyn_slice::{DynTakeSlice, DynTakeSliceMut, DynSlice, DynSliceMut};
+pub use error::OutOfBounds;
+#[cfg(feature = "derive")]
+pub use owned_slice_derive::TakeSlice;
+#[cfg(feature = "alloc")]
+pub use flat::FlatSlice;
+pub use fmt::HexDump;
+pub use grid::{TakeSlice2D, Slice2D, Slice2DMut, Row, RowMut, Rows, RowsMut};
+#[cfg(feature = "heapless")]
+pub use heapless_impls::IndexedDeque;
+pub use indexed::IndexedSlice;
+pub use iter::{Iter, IterMut, IterIndexed, IterIndexedMut};
+pub use lending::{LendingIterator, WindowsMut};
+pub use map::{MapView, MapViews};
+pub use masked::{MaskedSlice, MaskedIter};
+pub use owned::OwnedSlice;
+#[cfg(feature = "rayon")]
+pub use parallel::{ParIter, ParIterMut, ParChunksMut};
+pub use permuted::PermutedSlice;
+pub use policy::{BoundsPolicy, SafePolicy, Checked, DebugChecked, Clamped};
+pub use reversed::{Reversed, ReversedMut};
+#[cfg(feature = "serde")]
+pub use serde_impls::DeserializeInto;
+#[cfg(feature = "alloc")]
+pub use shared::{SharedSlice, RcSlice, ArcSlice};
+#[cfg(feature = "slab")]
+pub use slab_impls::IterOccupied;
+pub use split::{Split, SplitMut, SplitInclusive, RSplit, SplitN, ChunkBy};
+pub use step::Step;
+pub use strided::{StridedSlice, StridedSliceMut, Deinterleave, DeinterleaveMut};
+#[cfg(feature = "std")]
+pub use sync::{LockedSlice, RwLockedSlice, SliceGuard};
+pub use wrapping::{WrappingSlice, WrappingSliceMut};
+pub use zip::{Zip, ZipMut};
+use error::{check_in_bounds, check_index};
+use util::{assert_in_bounds, to_range, try_to_range, to_range_saturating, to_range_from_end, clamp_range, offset, span, predecessor};
 
-pub use iter::{Iter, IterMut};
-use util::{unlikely, assert_in_bounds};
+// lets the code generated by `#[derive(TakeSlice)]`
+// name these traits, whichever edition the calling crate uses
+#[doc(hidden)]
+pub mod __private {
+    pub use core::ops::{Index, IndexMut};
+}
 
 /// This trait looks similar to the `Num` trait from `num`, however it doesn't
 /// require things like `Mul`, `Div`, `Rem` and `from_str_radix`.
 /// In addition, it is automatically implemented, whereas you'd have to implement `Num` manually.
+///
+/// `NumCast` is used to convert between indices and `usize`,
+/// for instance to give iterators an exact `size_hint`.
+///
+/// Slicing, indexing and iterating only need `Step`, which is all that
+/// newtypes around integers get from `index_newtype!`.
 pub trait Idx
-    : Add<Self, Output = Self> + Sub<Self, Output = Self> + Zero + One + Eq + Ord + Debug + Copy
+    : Step + Add<Self, Output = Self> + Sub<Self, Output = Self> + Zero + One + Eq + Ord + NumCast + Debug + Copy
     {
 }
 
 impl<T: Add<Self, Output=Self>
       + Sub<Self, Output=Self>
       + Zero + One + Eq + Ord
-      + Debug + Copy> Idx for T {}
+      + NumCast + Debug + Copy> Idx for T {}
 
 /// Represents an immutable slice into another data structure, like &[T].
-#[derive(Copy, Clone, Debug)]
-pub struct Slice<'a, K: 'a + Index<I, Output = T>, I: 'a + Idx, T: 'a> {
+pub struct Slice<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Step, T: 'a, P: BoundsPolicy = Checked> {
     list: &'a K,
     start: I,
     len: I,
-    ty: marker::PhantomData<T>,
+    ty: marker::PhantomData<(T, P)>,
 }
 
 impl<'a, K, I, T> Slice<'a, K, I, T>
-    where K: Index<I, Output = T>,
-          I: Idx
+    where K: ?Sized + Index<I, Output = T>,
+          I: Step
 {
     pub fn new(list: &'a K, index: Range<I>) -> Slice<'a, K, I, T> {
+        Slice::from_range(list, index)
+    }
+
+    /// Creates a slice of `len` elements starting at `start`,
+    /// without checking it against the length of the container.
+    ///
+    /// # Safety
+    ///
+    /// `start + len` must not exceed the length of `list`.
+    #[inline]
+    pub unsafe fn new_unchecked(list: &'a K, start: I, len: I) -> Slice<'a, K, I, T> {
         Slice {
-            list: list,
+            list,
+            start,
+            len,
+            ty: marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, K, I, T, P> Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Step,
+          P: BoundsPolicy
+{
+    #[inline]
+    fn from_range(list: &'a K, index: Range<I>) -> Slice<'a, K, I, T, P> {
+        Slice {
+            list,
             start: index.start,
-            len: index.end - index.start,
+            len: span(index.start, index.end),
             ty: marker::PhantomData,
         }
     }
 
+    /// Returns the number of elements in the slice.
+    #[inline]
+    pub fn len(&self) -> I {
+        self.len
+    }
+
+    /// Returns `true` if the slice has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == I::zero()
+    }
+
+    /// Returns a reference to the element at `index`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: I) -> Option<&'a T> {
+        if index < self.len {
+            Some(&self.list[offset(self.start, index)])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the element at `index`,
+    /// or an `OutOfBounds` error describing the failed access.
+    #[inline]
+    pub fn try_get(&self, index: I) -> Result<&'a T, OutOfBounds<I>> {
+        check_index(index, self.len)?;
+        Ok(&self.list[offset(self.start, index)])
+    }
+
+    /// Returns a reference to the element at `index` without checking it
+    /// against the length of the slice. Only the container's own `Index`
+    /// implementation is left to check it.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than `self.len()`.
+    #[inline]
+    pub unsafe fn get_unchecked(&self, index: I) -> &'a T {
+        &self.list[offset(self.start, index)]
+    }
+
+    /// Returns the first element of the slice, or `None` if it is empty.
+    #[inline]
+    pub fn first(&self) -> Option<&'a T> {
+        self.get(I::zero())
+    }
+
+    /// Returns the last element of the slice, or `None` if it is empty.
+    #[inline]
+    pub fn last(&self) -> Option<&'a T> {
+        if self.is_empty() {
+            None
+        } else {
+            self.get(predecessor(self.len))
+        }
+    }
+
+    /// Divides one slice into two at an index.
+    ///
+    /// The first will contain all indices from `[0, mid)` and the second
+    /// will contain all indices from `[mid, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn split_at(self, mid: I) -> (Self, Self) {
+        assert_in_bounds(&(I::zero()..mid), self.len);
+        (Slice::from_range(self.list, self.start..offset(self.start, mid)),
+         Slice::from_range(self.list, offset(self.start, mid)..offset(self.start, self.len)))
+    }
+
+    /// Returns the first element and a slice of the rest,
+    /// or `None` if the slice is empty.
+    pub fn split_first(self) -> Option<(&'a T, Self)> {
+        self.first().map(|first| (first, self.subslice_from(I::zero().successor()..)))
+    }
+
+    /// Returns the last element and a slice of the rest,
+    /// or `None` if the slice is empty.
+    pub fn split_last(self) -> Option<(&'a T, Self)> {
+        self.last().map(|last| (last, self.subslice_to(..predecessor(self.len))))
+    }
+
+    /// Narrows the view to `range`, which is relative to this slice
+    /// rather than to the underlying container.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of the bounds of this slice.
+    pub fn subslice(&self, range: Range<I>) -> Slice<'a, K, I, T, P> {
+        assert_in_bounds(&range, self.len);
+        Slice::from_range(self.list, offset(self.start, range.start)..offset(self.start, range.end))
+    }
+
+    /// Narrows the view to start at `range.start`.
+    pub fn subslice_from(&self, range: RangeFrom<I>) -> Slice<'a, K, I, T, P> {
+        self.subslice(range.start..self.len)
+    }
+
+    /// Narrows the view to end at `range.end`.
+    pub fn subslice_to(&self, range: RangeTo<I>) -> Slice<'a, K, I, T, P> {
+        self.subslice(I::zero()..range.end)
+    }
+
     pub fn iter(self) -> Iter<'a, K, I, T> {
         Iter::new(self)
     }
+
+    /// Returns an iterator yielding each element together with
+    /// its index into the slice.
+    pub fn iter_indexed(self) -> IterIndexed<'a, K, I, T> {
+        IterIndexed::new(self)
+    }
+
+    /// Changes how the slice checks indices; see `BoundsPolicy`.
+    pub fn with_policy<Q: SafePolicy>(self) -> Slice<'a, K, I, T, Q> {
+        unsafe { self.with_policy_unchecked() }
+    }
+
+    /// Changes how the slice checks indices to any `BoundsPolicy`,
+    /// including `DebugChecked`.
+    ///
+    /// # Safety
+    ///
+    /// Unless `Q` is a `SafePolicy`, every index used with `[]` on the
+    /// returned slice, or on any slice split off from it, must be less
+    /// than the length of that slice.
+    pub unsafe fn with_policy_unchecked<Q: BoundsPolicy>(self) -> Slice<'a, K, I, T, Q> {
+        Slice {
+            list: self.list,
+            start: self.start,
+            len: self.len,
+            ty: marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, K, I, T, P> Clone for Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Step,
+          P: BoundsPolicy
+{
+    fn clone(&self) -> Self {
+        *self
+    }
 }
 
-impl<'a, K, I, T> Index<I> for Slice<'a, K, I, T>
-    where K: Index<I, Output = T>,
-          I: Idx
+impl<'a, K, I, T, P> Copy for Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Step,
+          P: BoundsPolicy
+{
+}
+
+impl<'a, K, I, T, P> Index<I> for Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Step,
+          P: BoundsPolicy
 {
     type Output = T;
 
     #[inline]
     fn index(&self, index: I) -> &T {
-        if unlikely(index >= self.len) {
-            panic!("Index out of bounds: {:?} >= {:?}", index, self.len);
-        }
-        &self.list[self.start + index]
+        let index = P::check_index(index, self.len);
+        &self.list[offset(self.start, index)]
     }
 }
 
 /// Represents an immutable slice into another data structure, like &mut [T].
-pub struct SliceMut<'a, K: 'a + IndexMut<I, Output = T>, I: 'a + Idx, T: 'a> {
-    list: &'a mut K,
+pub struct SliceMut<'a, K: 'a + ?Sized + IndexMut<I, Output = T>, I: 'a + Step, T: 'a, P: BoundsPolicy = Checked> {
+    // a raw pointer rather than `&'a mut K`, so that `split_at_mut`
+    // can hand out two views over the same container. This also keeps
+    // `SliceMut` from being `Send` or `Sync`: views of one container each
+    // borrow it as `&mut K` to reach an element, so they can't be used
+    // from different threads at once.
+    list: *mut K,
     start: I,
     len: I,
-    ty: marker::PhantomData<T>,
+    ty: marker::PhantomData<(&'a mut K, T, P)>,
 }
 
 impl<'a, K, I, T> SliceMut<'a, K, I, T>
-    where K: IndexMut<I, Output = T>,
-          I: Idx
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Step
 {
     pub fn new(list: &'a mut K, index: Range<I>) -> SliceMut<'a, K, I, T> {
+        SliceMut::from_range(list, index)
+    }
+
+    /// Creates a mutable slice of `len` elements starting at `start`,
+    /// without checking it against the length of the container.
+    ///
+    /// # Safety
+    ///
+    /// `start + len` must not exceed the length of `list`.
+    #[inline]
+    pub unsafe fn new_unchecked(list: &'a mut K, start: I, len: I) -> SliceMut<'a, K, I, T> {
         SliceMut {
-            list: list,
+            list: list as *mut K,
+            start,
+            len,
+            ty: marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, K, I, T, P> SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Step,
+          P: BoundsPolicy
+{
+    #[inline]
+    fn from_range(list: &'a mut K, index: Range<I>) -> SliceMut<'a, K, I, T, P> {
+        SliceMut {
+            list: list as *mut K,
             start: index.start,
-            len: index.end - index.start,
+            len: span(index.start, index.end),
+            ty: marker::PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the slice.
+    #[inline]
+    pub fn len(&self) -> I {
+        self.len
+    }
+
+    /// Returns `true` if the slice has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == I::zero()
+    }
+
+    /// Returns a reference to the element at `index`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: I) -> Option<&T> {
+        if index < self.len {
+            Some(&self.list()[offset(self.start, index)])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element at `index`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: I) -> Option<&mut T> {
+        if index < self.len {
+            let start = self.start;
+            Some(&mut self.list_mut()[offset(start, index)])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the element at `index`,
+    /// or an `OutOfBounds` error describing the failed access.
+    #[inline]
+    pub fn try_get(&self, index: I) -> Result<&T, OutOfBounds<I>> {
+        check_index(index, self.len)?;
+        Ok(&self.list()[offset(self.start, index)])
+    }
+
+    /// Returns a mutable reference to the element at `index`,
+    /// or an `OutOfBounds` error describing the failed access.
+    #[inline]
+    pub fn try_get_mut(&mut self, index: I) -> Result<&mut T, OutOfBounds<I>> {
+        check_index(index, self.len)?;
+        let start = self.start;
+        Ok(&mut self.list_mut()[offset(start, index)])
+    }
+
+    /// Returns a reference to the element at `index` without checking it
+    /// against the length of the slice. Only the container's own `Index`
+    /// implementation is left to check it.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than `self.len()`.
+    #[inline]
+    pub unsafe fn get_unchecked(&self, index: I) -> &T {
+        &self.list()[offset(self.start, index)]
+    }
+
+    /// Returns a mutable reference to the element at `index` without checking
+    /// it against the length of the slice. Only the container's own `IndexMut`
+    /// implementation is left to check it.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than `self.len()`.
+    #[inline]
+    pub unsafe fn get_unchecked_mut(&mut self, index: I) -> &mut T {
+        let start = self.start;
+        &mut self.list_mut()[offset(start, index)]
+    }
+
+    /// Returns the first element of the slice, or `None` if it is empty.
+    #[inline]
+    pub fn first(&self) -> Option<&T> {
+        self.get(I::zero())
+    }
+
+    /// Returns the last element of the slice, or `None` if it is empty.
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else {
+            self.get(predecessor(self.len))
+        }
+    }
+
+    /// Returns a mutable reference to the first element of the slice,
+    /// or `None` if it is empty.
+    #[inline]
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(I::zero())
+    }
+
+    /// Returns a mutable reference to the last element of the slice,
+    /// or `None` if it is empty.
+    #[inline]
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        if self.is_empty() {
+            None
+        } else {
+            let last = predecessor(self.len);
+            self.get_mut(last)
+        }
+    }
+
+    /// Divides one mutable slice into two at an index.
+    ///
+    /// The first will contain all indices from `[0, mid)` and the second
+    /// will contain all indices from `[mid, len)`. Both halves may be used
+    /// at the same time, which is why the container must implement
+    /// `UniqueIndexMut`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn split_at_mut(self, mid: I) -> (Self, Self)
+        where K: UniqueIndexMut<I>
+    {
+        assert_in_bounds(&(I::zero()..mid), self.len);
+        (SliceMut {
+            list: self.list,
+            start: self.start,
+            len: mid,
             ty: marker::PhantomData,
+        },
+         SliceMut {
+            list: self.list,
+            start: offset(self.start, mid),
+            len: span(mid, self.len),
+            ty: marker::PhantomData,
+        })
+    }
+
+    /// Returns the first element and a mutable slice of the rest,
+    /// or `None` if the slice is empty.
+    pub fn split_first_mut(self) -> Option<(&'a mut T, Self)>
+        where K: UniqueIndexMut<I>
+    {
+        if self.is_empty() {
+            return None;
         }
+        let (first, rest) = self.split_at_mut(I::zero().successor());
+        first.iter_mut().next().map(|first| (first, rest))
     }
 
-    pub fn iter_mut(self) -> IterMut<'a, K, I, T> {
+    /// Returns the last element and a mutable slice of the rest,
+    /// or `None` if the slice is empty.
+    pub fn split_last_mut(self) -> Option<(&'a mut T, Self)>
+        where K: UniqueIndexMut<I>
+    {
+        if self.is_empty() {
+            return None;
+        }
+        let mid = predecessor(self.len);
+        let (rest, last) = self.split_at_mut(mid);
+        last.iter_mut().next().map(|last| (last, rest))
+    }
+
+    /// Narrows the view to `range`, which is relative to this slice
+    /// rather than to the underlying container.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of the bounds of this slice.
+    pub fn subslice_mut(&mut self, range: Range<I>) -> SliceMut<'_, K, I, T, P> {
+        assert_in_bounds(&range, self.len);
+        let start = self.start;
+        SliceMut::from_range(self.list_mut(), offset(start, range.start)..offset(start, range.end))
+    }
+
+    /// Narrows the view to start at `range.start`.
+    pub fn subslice_from_mut(&mut self, range: RangeFrom<I>) -> SliceMut<'_, K, I, T, P> {
+        let len = self.len;
+        self.subslice_mut(range.start..len)
+    }
+
+    /// Narrows the view to end at `range.end`.
+    pub fn subslice_to_mut(&mut self, range: RangeTo<I>) -> SliceMut<'_, K, I, T, P> {
+        self.subslice_mut(I::zero()..range.end)
+    }
+
+    /// Splits the slice into one mutable view per range, or returns `None`
+    /// if any range is out of bounds or overlaps another.
+    /// The ranges may be given in any order, and empty ranges never overlap.
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use owned_slice::TakeSlice;
+    ///
+    /// let mut deque: VecDeque<u32> = (0..8).collect();
+    /// let [mut a, mut b] = deque.index_range_full_mut(..)
+    ///     .get_disjoint_mut([5..8, 0..3])
+    ///     .unwrap();
+    /// a.swap_with_slice(&mut b);
+    /// assert_eq!(deque, [5, 6, 7, 3, 4, 0, 1, 2]);
+    /// ```
+    pub fn get_disjoint_mut<const N: usize>(self, ranges: [Range<I>; N]) -> Option<[SliceMut<'a, K, I, T, P>; N]>
+        where K: UniqueIndexMut<I>
+    {
+        for (n, range) in ranges.iter().enumerate() {
+            if check_in_bounds(range, self.len).is_err() {
+                return None;
+            }
+            let overlaps = ranges[..n].iter().any(|other| {
+                range.start < range.end && other.start < other.end &&
+                range.start < other.end && other.start < range.end
+            });
+            if overlaps {
+                return None;
+            }
+        }
+        Some(ranges.map(|range| SliceMut {
+            list: self.list,
+            start: offset(self.start, range.start),
+            len: span(range.start, range.end),
+            ty: marker::PhantomData,
+        }))
+    }
+
+    /// Returns an iterator over shared references to the elements.
+    pub fn iter(&self) -> Iter<'_, K, I, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns an iterator over mutable references to the elements.
+    pub fn iter_mut(self) -> IterMut<'a, K, I, T>
+        where K: UniqueIndexMut<I>
+    {
         IterMut::new(self)
     }
+
+    /// Returns an iterator yielding each element together with
+    /// its index into the slice.
+    pub fn iter_indexed_mut(self) -> IterIndexedMut<'a, K, I, T>
+        where K: UniqueIndexMut<I>
+    {
+        IterIndexedMut::new(self)
+    }
+
+    /// Changes how the slice checks indices; see `BoundsPolicy`.
+    pub fn with_policy<Q: SafePolicy>(self) -> SliceMut<'a, K, I, T, Q> {
+        unsafe { self.with_policy_unchecked() }
+    }
+
+    /// Changes how the slice checks indices to any `BoundsPolicy`,
+    /// including `DebugChecked`.
+    ///
+    /// # Safety
+    ///
+    /// Unless `Q` is a `SafePolicy`, every index used with `[]` on the
+    /// returned slice, or on any slice split off from it, must be less
+    /// than the length of that slice. Otherwise two views could hand out
+    /// the same element.
+    pub unsafe fn with_policy_unchecked<Q: BoundsPolicy>(self) -> SliceMut<'a, K, I, T, Q> {
+        SliceMut {
+            list: self.list,
+            start: self.start,
+            len: self.len,
+            ty: marker::PhantomData,
+        }
+    }
+
+    /// Views the same elements through an immutable slice,
+    /// borrowed from `self`.
+    #[inline]
+    pub fn as_slice(&self) -> Slice<'_, K, I, T, P> {
+        Slice::from_range(self.list(), self.start..offset(self.start, self.len))
+    }
+
+    /// Reborrows the slice for a shorter lifetime, like `&mut *slice`
+    /// does for `&mut [T]`, so it can be passed on without being moved.
+    #[inline]
+    pub fn reborrow(&mut self) -> SliceMut<'_, K, I, T, P> {
+        SliceMut {
+            list: self.list,
+            start: self.start,
+            len: self.len,
+            ty: marker::PhantomData,
+        }
+    }
+
+    #[inline]
+    fn list(&self) -> &K {
+        unsafe { &*self.list }
+    }
+
+    #[inline]
+    fn list_mut(&mut self) -> &mut K {
+        unsafe { &mut *self.list }
+    }
 }
 
-impl<'a, K, I, T> Index<I> for SliceMut<'a, K, I, T>
-    where K: IndexMut<I, Output = T>,
-          I: Idx
+impl<'a, K, I, T, P> Index<I> for SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Step,
+          P: BoundsPolicy
 {
     type Output = T;
 
     #[inline]
     fn index(&self, index: I) -> &T {
-        if unlikely(index >= self.len) {
-            panic!("Index out of bounds: {:?} >= {:?}", index, self.len);
-        }
-        &self.list[self.start + index]
+        let index = P::check_index(index, self.len);
+        &self.list()[offset(self.start, index)]
     }
 }
 
-impl<'a, K, I, T> IndexMut<I> for SliceMut<'a, K, I, T>
-    where K: IndexMut<I, Output = T>,
-          I: Idx
+impl<'a, K, I, T, P> IndexMut<I> for SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Step,
+          P: BoundsPolicy
 {
     #[inline]
     fn index_mut(&mut self, index: I) -> &mut T {
-        if unlikely(index >= self.len) {
-            panic!("Index out of bounds: {:?} >= {:?}", index, self.len);
-        }
-        &mut self.list[self.start + index]
+        let index = P::check_index(index, self.len);
+        let start = self.start;
+        &mut self.list_mut()[offset(start, index)]
     }
 }
 
 ///  Mimics the built in slices [T] for various built-in types
 /// and also your own custom data structures.
-pub trait TakeSlice<T, I>: Index<I, Output = T> + IndexMut<I> + Sized
-    where I: Idx
+///
+/// Only `Index` is required; the `*_mut` methods are available
+/// when the container also implements `IndexMut`.
+///
+/// `TakeSlice` is not implemented for `&K`, `&mut K`, `Box<K>`, `Rc<K>` or `Arc<K>`,
+/// because none of them implement `Index` and the orphan rules prevent
+/// this crate from adding it. Method calls auto-deref through them anyway;
+/// to accept any of them generically, bound on `Deref` instead:
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use std::ops::Deref;
+/// use std::rc::Rc;
+/// use owned_slice::TakeSlice;
+///
+/// fn sum_tail<C, K>(container: C) -> u32
+///     where C: Deref<Target = K>,
+///           K: ?Sized + TakeSlice<u32, usize>
+/// {
+///     container.index_range_from(1..).iter().sum()
+/// }
+///
+/// let v: VecDeque<u32> = (0..4).collect();
+/// assert_eq!(sum_tail(&v), 6);
+/// assert_eq!(sum_tail(Box::new(vec![1, 2, 3])), 5);
+/// assert_eq!(sum_tail(Rc::new([5, 5])), 5);
+/// ```
+pub trait TakeSlice<T, I>: Index<I, Output = T>
+    where I: Step
 {
     /// Slice the structure with a range.
     /// Equivalent to `&container[start..end]`
-    fn index_range(&self, index: Range<I>) -> Slice<Self, I, T> {
+    fn index_range(&self, index: Range<I>) -> Slice<'_, Self, I, T> {
         assert_in_bounds(&index, self.len());
-        Slice {
-            list: self,
-            start: index.start,
-            len: index.end - index.start,
-            ty: marker::PhantomData,
-        }
+        Slice::new(self, index)
     }
 
     /// Slice the structure with a range, returning a mutable reference.
     /// Equivalent to `&mut container[start..end]`
-    fn index_range_mut(&mut self, index: Range<I>) -> SliceMut<Self, I, T> {
+    fn index_range_mut(&mut self, index: Range<I>) -> SliceMut<'_, Self, I, T>
+        where Self: IndexMut<I>
+    {
         assert_in_bounds(&index, self.len());
-        SliceMut {
-            list: self,
-            start: index.start,
-            len: index.end - index.start,
-            ty: marker::PhantomData,
-        }
+        SliceMut::new(self, index)
     }
 
     /// Slice the structure from the beginning to the specified index.
     /// Equivalent to `&container[..end]`
-    fn index_range_to(&self, index: RangeTo<I>) -> Slice<Self, I, T> {
-        self.index_range(Zero::zero()..index.end)
+    fn index_range_to(&self, index: RangeTo<I>) -> Slice<'_, Self, I, T> {
+        self.index_range(I::zero()..index.end)
     }
 
     /// Slice the structure from the beginning to the specified index,
     /// returning a mutable reference.
     /// Equivalent to `&mut container[..end]`
-    fn index_range_to_mut(&mut self, index: RangeTo<I>) -> SliceMut<Self, I, T> {
-        self.index_range_mut(Zero::zero()..index.end)
+    fn index_range_to_mut(&mut self, index: RangeTo<I>) -> SliceMut<'_, Self, I, T>
+        where Self: IndexMut<I>
+    {
+        self.index_range_mut(I::zero()..index.end)
     }
 
     /// Slice the structure from the specified index to the end.
     /// Equivalent to `&container[start..]`
-    fn index_range_from(&self, index: RangeFrom<I>) -> Slice<Self, I, T> {
+    fn index_range_from(&self, index: RangeFrom<I>) -> Slice<'_, Self, I, T> {
         let len = self.len();
         self.index_range(index.start..len)
     }
@@ -262,26 +968,357 @@ pub trait TakeSlice<T, I>: Index<I, Output = T> + IndexMut<I> + Sized
     /// Slice the structure from the specified index to the end,
     /// returning a mutable reference.
     /// Equivalent to `&mut container[start..]`
-    fn index_range_from_mut(&mut self, index: RangeFrom<I>) -> SliceMut<Self, I, T> {
+    fn index_range_from_mut(&mut self, index: RangeFrom<I>) -> SliceMut<'_, Self, I, T>
+        where Self: IndexMut<I>
+    {
         let len = self.len();
         self.index_range_mut(index.start..len)
     }
 
+    /// Slice the structure with an inclusive range.
+    /// Equivalent to `&container[start..=end]`
+    fn index_range_inclusive(&self, index: RangeInclusive<I>) -> Slice<'_, Self, I, T> {
+        self.index_range(to_range(&index, self.len()))
+    }
+
+    /// Slice the structure with an inclusive range, returning a mutable reference.
+    /// Equivalent to `&mut container[start..=end]`
+    fn index_range_inclusive_mut(&mut self, index: RangeInclusive<I>) -> SliceMut<'_, Self, I, T>
+        where Self: IndexMut<I>
+    {
+        let index = to_range(&index, self.len());
+        self.index_range_mut(index)
+    }
+
+    /// Slice the structure from the beginning up to and including the specified index.
+    /// Equivalent to `&container[..=end]`
+    fn index_range_to_inclusive(&self, index: RangeToInclusive<I>) -> Slice<'_, Self, I, T> {
+        self.index_range(to_range(&index, self.len()))
+    }
+
+    /// Slice the structure from the beginning up to and including the specified index,
+    /// returning a mutable reference.
+    /// Equivalent to `&mut container[..=end]`
+    fn index_range_to_inclusive_mut(&mut self, index: RangeToInclusive<I>) -> SliceMut<'_, Self, I, T>
+        where Self: IndexMut<I>
+    {
+        let index = to_range(&index, self.len());
+        self.index_range_mut(index)
+    }
+
+    /// Slice the entire structure.
+    /// Equivalent to `&container[..]`
+    fn index_range_full(&self, _index: RangeFull) -> Slice<'_, Self, I, T> {
+        let len = self.len();
+        self.index_range(I::zero()..len)
+    }
+
+    /// Slice the entire structure, returning a mutable reference.
+    /// Equivalent to `&mut container[..]`
+    fn index_range_full_mut(&mut self, _index: RangeFull) -> SliceMut<'_, Self, I, T>
+        where Self: IndexMut<I>
+    {
+        let len = self.len();
+        self.index_range_mut(I::zero()..len)
+    }
+
+    /// Slice the structure with a range, taking ownership of it.
+    /// The container can be recovered with `OwnedSlice::into_inner`.
+    fn into_slice(self, index: Range<I>) -> OwnedSlice<Self, I, T>
+        where Self: Sized,
+              I: Idx
+    {
+        assert_in_bounds(&index, self.len());
+        OwnedSlice::new(self, index)
+    }
+
+    /// Slice the structure with any kind of range.
+    /// Equivalent to `&container[range]`, e.g. `&container[a..=b]` or `&container[..]`
+    fn take_slice<R: RangeBounds<I>>(&self, range: R) -> Slice<'_, Self, I, T> {
+        let index = to_range(&range, self.len());
+        self.index_range(index)
+    }
+
+    /// Slice the structure with any kind of range, returning a mutable reference.
+    /// Equivalent to `&mut container[range]`, e.g. `&mut container[a..=b]` or `&mut container[..]`
+    fn take_slice_mut<R: RangeBounds<I>>(&mut self, range: R) -> SliceMut<'_, Self, I, T>
+        where Self: IndexMut<I>
+    {
+        let index = to_range(&range, self.len());
+        self.index_range_mut(index)
+    }
+
+    /// Slice the structure with any kind of range, clamped to the bounds
+    /// of the container rather than panicking, so the slice may be shorter
+    /// than requested.
+    /// Equivalent to `&container[min(start, len)..min(end, len)]`
+    fn index_range_clamped<R: RangeBounds<I>>(&self, range: R) -> Slice<'_, Self, I, T> {
+        let index = clamp_range(to_range_saturating(&range, self.len()), self.len());
+        self.index_range(index)
+    }
+
+    /// Slice the structure with any kind of range, clamped to the bounds
+    /// of the container, returning a mutable reference.
+    fn index_range_clamped_mut<R: RangeBounds<I>>(&mut self, range: R) -> SliceMut<'_, Self, I, T>
+        where Self: IndexMut<I>
+    {
+        let index = clamp_range(to_range_saturating(&range, self.len()), self.len());
+        self.index_range_mut(index)
+    }
+
+    /// Slice the structure with any kind of range over a signed index,
+    /// where negative bounds count back from the end like in Python.
+    /// Equivalent to `&container[len - 3..]` for `-3..`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resolved range is out of bounds.
+    fn index_range_signed<R: RangeBounds<I>>(&self, range: R) -> Slice<'_, Self, I, T>
+        where I: Idx + Signed
+    {
+        let index = to_range_from_end(&range, self.len());
+        assert_in_bounds(&index, self.len());
+        Slice::new(self, index)
+    }
+
+    /// Slice the structure with any kind of range over a signed index,
+    /// where negative bounds count back from the end, returning a mutable reference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resolved range is out of bounds.
+    fn index_range_signed_mut<R: RangeBounds<I>>(&mut self, range: R) -> SliceMut<'_, Self, I, T>
+        where Self: IndexMut<I>,
+              I: Idx + Signed
+    {
+        let index = to_range_from_end(&range, self.len());
+        assert_in_bounds(&index, self.len());
+        SliceMut::new(self, index)
+    }
+
+    /// Takes every `step`-th element of any kind of range.
+    /// Equivalent to `container[range].iter().step_by(step)`, but keeps random access.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or `step` is zero.
+    fn slice_step<R: RangeBounds<I>>(&self, range: R, step: I) -> StridedSlice<'_, Self, I, T>
+        where I: Idx
+    {
+        let index = to_range(&range, self.len());
+        assert_in_bounds(&index, self.len());
+        StridedSlice::new(self, index, step)
+    }
+
+    /// Takes every `step`-th element of any kind of range, returning a mutable view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or `step` is zero.
+    fn slice_step_mut<R: RangeBounds<I>>(&mut self, range: R, step: I) -> StridedSliceMut<'_, Self, I, T>
+        where Self: IndexMut<I>,
+              I: Idx
+    {
+        let index = to_range(&range, self.len());
+        assert_in_bounds(&index, self.len());
+        StridedSliceMut::new(self, index, step)
+    }
+
+    /// Slice the structure with a range which wraps around past the end
+    /// of the container back to the start, like a ring buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is longer than the container.
+    fn wrapping_slice(&self, index: Range<I>) -> WrappingSlice<'_, Self, I, T>
+        where I: Idx
+    {
+        WrappingSlice::new(self, index)
+    }
+
+    /// Slice the structure with a range which wraps around past the end
+    /// of the container, returning a mutable view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is longer than the container.
+    fn wrapping_slice_mut(&mut self, index: Range<I>) -> WrappingSliceMut<'_, Self, I, T>
+        where Self: IndexMut<I>,
+              I: Idx
+    {
+        WrappingSliceMut::new(self, index)
+    }
+
+    /// Slice the structure with a range, returning an error
+    /// rather than panicking if the range is out of bounds.
+    fn try_index_range(&self, index: Range<I>) -> Result<Slice<'_, Self, I, T>, OutOfBounds<I>> {
+        check_in_bounds(&index, self.len())?;
+        Ok(Slice::new(self, index))
+    }
+
+    /// Slice the structure with a range, returning a mutable reference,
+    /// or an error if the range is out of bounds.
+    fn try_index_range_mut(&mut self, index: Range<I>) -> Result<SliceMut<'_, Self, I, T>, OutOfBounds<I>>
+        where Self: IndexMut<I>
+    {
+        check_in_bounds(&index, self.len())?;
+        Ok(SliceMut::new(self, index))
+    }
+
+    /// Slice the structure with any kind of range, returning an error
+    /// rather than panicking if the range is out of bounds.
+    fn try_take_slice<R: RangeBounds<I>>(&self, range: R) -> Result<Slice<'_, Self, I, T>, OutOfBounds<I>> {
+        let index = try_to_range(&range, self.len())?;
+        self.try_index_range(index)
+    }
+
+    /// Slice the structure with any kind of range, returning a mutable reference,
+    /// or an error if the range is out of bounds.
+    fn try_take_slice_mut<R: RangeBounds<I>>(&mut self, range: R) -> Result<SliceMut<'_, Self, I, T>, OutOfBounds<I>>
+        where Self: IndexMut<I>
+    {
+        let index = try_to_range(&range, self.len())?;
+        self.try_index_range_mut(index)
+    }
+
     /// Returns the number of elements in the container.
     /// Used for providing nicer out-of-bounds errors.
     fn len(&self) -> I;
+
+    /// Returns `true` if the container has a length of zero.
+    fn is_empty(&self) -> bool {
+        self.len() == I::zero()
+    }
 }
 
+/// Marker for containers whose `IndexMut` implementation hands out
+/// non-overlapping references for distinct indices.
+///
+/// # Safety
+///
+/// Implementors guarantee that for any two distinct in-bounds indices,
+/// `index_mut` returns references to disjoint memory, and that calling
+/// `index` or `index_mut` does not invalidate references previously returned
+/// for other indices. This is what allows `SliceMut::split_at_mut` to hand
+/// out two views that are used at the same time, and `IterMut` to hand out
+/// references which are all alive at once.
+///
+/// Views reach an element by briefly borrowing the whole container, so it
+/// is not enough for the elements to be disjoint: neither a `&Self` nor a
+/// `&mut Self` may cover the elements themselves, which rules out storing
+/// them inline, and `index`/`index_mut` must reach the element without
+/// borrowing any of the others, e.g. through a slice of the whole storage.
+/// `VecDeque` qualifies, as its elements live behind a raw pointer which
+/// `index_mut` offsets directly. `Vec`, `[T]` and arrays don't, so wrap them
+/// in an `IndexedSlice` to split or iterate them mutably.
+///
+/// This doesn't make it safe to index from several threads at once: every
+/// `index_mut` call needs a `&mut Self`, and two of those can't be alive at
+/// the same time, whatever the container does. To mutate elements in
+/// parallel, go through an `IndexedSlice`, whose views convert into `&mut [T]`.
+///
+/// ```compile_fail
+/// use std::collections::VecDeque;
+/// use owned_slice::TakeSlice;
+///
+/// let mut v: VecDeque<u32> = (0..4).collect();
+/// let (a, b) = v.index_range_mut(0..4).split_at_mut(2);
+/// std::thread::scope(|s| {
+///     s.spawn(move || a.into_iter().for_each(|x| *x += 1));
+///     s.spawn(move || b.into_iter().for_each(|x| *x += 1));
+/// });
+/// ```
+///
+/// Containers which only implement `IndexMut` can still be mutated one
+/// element at a time, but not iterated mutably:
+///
+/// ```compile_fail
+/// use std::ops::{Index, IndexMut};
+/// use owned_slice::TakeSlice;
+///
+/// // every index refers to the same element
+/// struct Single(u8);
+///
+/// impl Index<usize> for Single {
+///     type Output = u8;
+///     fn index(&self, _: usize) -> &u8 { &self.0 }
+/// }
+///
+/// impl IndexMut<usize> for Single {
+///     fn index_mut(&mut self, _: usize) -> &mut u8 { &mut self.0 }
+/// }
+///
+/// impl TakeSlice<u8, usize> for Single {
+///     fn len(&self) -> usize { 2 }
+/// }
+///
+/// let mut single = Single(0);
+/// let mut iter = single.index_range_mut(0..2).iter_mut();
+/// let (a, b) = (iter.next().unwrap(), iter.next().unwrap());
+/// ```
+pub unsafe trait UniqueIndexMut<I>: IndexMut<I> {}
+
+#[cfg(feature = "alloc")]
 impl<T> TakeSlice<T, usize> for VecDeque<T> {
     fn len(&self) -> usize {
         self.len()
     }
 }
 
+#[cfg(feature = "alloc")]
+unsafe impl<T> UniqueIndexMut<usize> for VecDeque<T> {}
+
+#[cfg(feature = "alloc")]
+impl<T> TakeSlice<T, usize> for Vec<T> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> TakeSlice<T, usize> for [T] {
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, const N: usize> TakeSlice<T, usize> for [T; N] {
+    fn len(&self) -> usize {
+        N
+    }
+}
+
+impl<'a, K, I, T, P> TakeSlice<T, I> for Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Step,
+          P: BoundsPolicy
+{
+    fn len(&self) -> I {
+        self.len
+    }
+}
+
+impl<'a, K, I, T, P> TakeSlice<T, I> for SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Step,
+          P: BoundsPolicy
+{
+    fn len(&self) -> I {
+        self.len
+    }
+}
+
+unsafe impl<'a, K, I, T, P> UniqueIndexMut<I> for SliceMut<'a, K, I, T, P>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Step,
+          P: BoundsPolicy
+{
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::VecDeque;
-    use TakeSlice;
+    use std::ops::Index;
+    use {TakeSlice, OutOfBounds, SliceMut, IndexedSlice, Step};
 
     fn test_vec() -> VecDeque<usize> {
         let mut v = VecDeque::new();
@@ -297,7 +1334,7 @@ mod tests {
     fn basic_slice_functionality() {
         let v = test_vec();
         let v = v.index_range(1..3);
-        let mut iter = v.clone().iter();
+        let mut iter = v.iter();
         assert_eq!(iter.next(), Some(&1));
         assert_eq!(iter.next(), Some(&2));
         assert_eq!(iter.next(), None);
@@ -305,6 +1342,464 @@ mod tests {
         assert_eq!(v[1], 2);
     }
 
+    fn sum<K: ?Sized + TakeSlice<usize, usize>>(list: &K) -> usize {
+        list.index_range_full(..).iter().sum()
+    }
+
+    #[test]
+    fn contiguous_containers() {
+        let mut vec = vec![0, 1, 2, 3, 4];
+        let mut array = [0, 1, 2, 3, 4];
+        assert_eq!(sum(&test_vec()), 10);
+        assert_eq!(sum(&vec), 10);
+        assert_eq!(sum(&array), 10);
+        assert_eq!(sum(&vec[1..]), 10);
+        vec.index_range_mut(1..3)[0] = 7;
+        IndexedSlice::new(&mut array).index_range_mut(1..3).reverse();
+        IndexedSlice::new(&mut vec[2..]).index_range_mut(1..3).reverse();
+        assert_eq!(vec, [0, 7, 2, 4, 3]);
+        assert_eq!(array, [0, 2, 1, 3, 4]);
+        assert!(array.try_index_range(3..6).is_err());
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derive_take_slice() {
+        #[derive(TakeSlice)]
+        #[take_slice(field = "secret", len = "self.secret.len() - 1")]
+        struct Custom<T> {
+            secret: VecDeque<T>,
+            _name: &'static str,
+        }
+
+        #[derive(TakeSlice)]
+        struct Wrapper(Vec<u32>);
+
+        let mut custom = Custom { secret: test_vec(), _name: "custom" };
+        assert_eq!(TakeSlice::len(&custom), 4);
+        assert_eq!(custom.index_range(1..3), [1, 2]);
+        custom.index_range_mut(0..4).reverse();
+        assert_eq!(custom[0], 3);
+        assert!(custom.try_index_range(0..5).is_err());
+
+        let wrapper = Wrapper(vec![5, 6, 7]);
+        assert_eq!(wrapper.index_range_from(1..), [6, 7]);
+    }
+
+    #[test]
+    fn slice_len() {
+        let mut v = test_vec();
+        assert_eq!(v.index_range(1..4).len(), 3);
+        assert!(!v.index_range(1..4).is_empty());
+        assert!(v.index_range(2..2).is_empty());
+        assert_eq!(v.index_range_mut(1..4).len(), 3);
+        assert!(v.index_range_mut(4..4).is_empty());
+    }
+
+    #[test]
+    fn get_checks_bounds() {
+        let mut v = test_vec();
+        assert_eq!(v.index_range(1..3).get(1), Some(&2));
+        assert_eq!(v.index_range(1..3).get(2), None);
+        {
+            let mut s = v.index_range_mut(1..3);
+            *s.get_mut(0).unwrap() = 10;
+            assert!(s.get_mut(2).is_none());
+        }
+        assert_eq!(v[1], 10);
+    }
+
+    #[test]
+    fn first_and_last() {
+        let mut v = test_vec();
+        assert_eq!(v.index_range(1..4).first(), Some(&1));
+        assert_eq!(v.index_range(1..4).last(), Some(&3));
+        assert_eq!(v.index_range(2..2).first(), None);
+        assert_eq!(v.index_range(2..2).last(), None);
+        {
+            let mut s = v.index_range_mut(1..4);
+            *s.first_mut().unwrap() = 10;
+            *s.last_mut().unwrap() = 30;
+        }
+        assert_eq!(v.index_range(0..5).first(), Some(&0));
+        assert_eq!(v[1], 10);
+        assert_eq!(v[3], 30);
+    }
+
+    #[test]
+    fn split_at() {
+        let mut v = test_vec();
+        {
+            let (a, b) = v.index_range(1..4).split_at(1);
+            assert_eq!(a.len(), 1);
+            assert_eq!(a[0], 1);
+            assert_eq!(b.len(), 2);
+            assert_eq!(b[1], 3);
+        }
+        {
+            let (mut a, mut b) = v.index_range_mut(0..5).split_at_mut(2);
+            let x = &mut a[1];
+            let y = &mut b[0];
+            ::std::mem::swap(x, y);
+        }
+        assert_eq!(v[1], 2);
+        assert_eq!(v[2], 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_at_out_of_bounds() {
+        let v = test_vec();
+        v.index_range(1..3).split_at(3);
+    }
+
+    #[test]
+    fn take_slice_any_range() {
+        let mut v = test_vec();
+        assert_eq!(v.take_slice(..).len(), 5);
+        assert_eq!(v.take_slice(1..3).len(), 2);
+        assert_eq!(v.take_slice(1..=3).last(), Some(&3));
+        assert_eq!(v.take_slice(..=1).len(), 2);
+        assert_eq!(v.take_slice(2..).first(), Some(&2));
+        v.take_slice_mut(3..)[1] = 40;
+        assert_eq!(v[4], 40);
+    }
+
+    #[test]
+    fn inclusive_and_full_ranges() {
+        let mut v = test_vec();
+        assert_eq!(v.index_range_inclusive(1..=3).len(), 3);
+        assert_eq!(v.index_range_inclusive(1..=3)[2], 3);
+        assert_eq!(v.index_range_to_inclusive(..=0).len(), 1);
+        assert_eq!(v.index_range_full(..).len(), 5);
+        v.index_range_inclusive_mut(4..=4)[0] = 40;
+        v.index_range_to_inclusive_mut(..=1)[0] = 10;
+        v.index_range_full_mut(..)[2] = 20;
+        assert_eq!(v, vec![10, 1, 20, 3, 40]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn inclusive_out_of_bounds() {
+        let v = test_vec();
+        v.index_range_inclusive(1..=5);
+    }
+
+    #[test]
+    fn fallible_accessors() {
+        let mut v = test_vec();
+        assert_eq!(v.try_index_range(1..3).map(|s| s.len()), Ok(2));
+        let err = v.try_index_range(2..7).unwrap_err();
+        assert_eq!(err, OutOfBounds { range: 2..7, len: 5 });
+        assert_eq!(err.to_string(), "Range out of bounds: 2..7 is not a subset of 0..5");
+        let (start, end) = (3, 2);
+        assert!(v.try_index_range(start..end).is_err());
+        assert!(v.try_take_slice(..=5).is_err());
+        assert_eq!(v.index_range(1..3).try_get(1), Ok(&2));
+        assert_eq!(v.index_range(1..3).try_get(2),
+                   Err(OutOfBounds { range: 2..3, len: 2 }));
+        let mut s = v.try_index_range_mut(0..2).unwrap();
+        *s.try_get_mut(1).unwrap() = 10;
+        assert!(s.try_get_mut(2).is_err());
+    }
+
+    #[test]
+    fn double_ended_iteration() {
+        let mut v = test_vec();
+        let rev: Vec<_> = v.index_range(1..4).iter().rev().cloned().collect();
+        assert_eq!(rev, vec![3, 2, 1]);
+        let mut iter = v.index_range(0..3).iter();
+        assert_eq!(iter.next_back(), Some(&2));
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next_back(), Some(&1));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        for (i, x) in v.index_range_mut(0..5).iter_mut().rev().enumerate() {
+            *x = i;
+        }
+        assert_eq!(v, vec![4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn exact_size_iteration() {
+        let mut v = test_vec();
+        let mut iter = v.index_range(1..4).iter();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.len(), 1);
+        let mut iter = v.index_range_mut(0..5).iter_mut();
+        assert_eq!(iter.len(), 5);
+        iter.next();
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+    }
+
+    #[test]
+    fn iter_mut_references_coexist() {
+        let mut v = test_vec();
+        let refs: Vec<_> = v.index_range_mut(1..5).iter_mut().collect();
+        for (i, x) in refs.into_iter().enumerate() {
+            *x += i * 10;
+        }
+        assert_eq!(v, vec![0, 1, 12, 23, 34]);
+    }
+
+    #[test]
+    fn specialized_iterator_methods() {
+        let mut v = test_vec();
+        assert_eq!(v.index_range(1..4).iter().fold(0, |acc, x| acc * 10 + x), 123);
+        assert_eq!(v.index_range(1..4).iter().count(), 3);
+        assert_eq!(v.index_range(1..4).iter().last(), Some(&3));
+        assert_eq!(v.index_range(2..2).iter().last(), None);
+        let mut iter = v.index_range(0..5).iter();
+        assert_eq!(iter.nth(2), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.nth(5), None);
+        assert_eq!(iter.next(), None);
+        v.index_range_mut(1..4).iter_mut().fold((), |_, x| *x *= 10);
+        assert_eq!(v, vec![0, 10, 20, 30, 4]);
+        let mut iter = v.index_range_mut(0..5).iter_mut();
+        assert_eq!(iter.nth(1).map(|x| *x), Some(10));
+        assert_eq!(iter.last().map(|x| *x), Some(4));
+    }
+
+    #[test]
+    fn iterate_by_reference() {
+        let mut v = test_vec();
+        {
+            let s = v.index_range(1..3);
+            let mut total = 0;
+            for x in &s {
+                total += *x;
+            }
+            assert_eq!(total, 3);
+            assert_eq!(s.len(), 2);
+        }
+        let mut s = v.index_range_mut(1..3);
+        for x in &mut s {
+            *x += 1;
+        }
+        let collected: Vec<_> = (&s).into_iter().cloned().collect();
+        assert_eq!(collected, vec![2, 3]);
+        assert_eq!(s.iter().count(), 2);
+    }
+
+    #[test]
+    fn indexed_iteration() {
+        let mut v = test_vec();
+        let pairs: Vec<_> = v.index_range(2..5).iter_indexed().collect();
+        assert_eq!(pairs, vec![(0, &2), (1, &3), (2, &4)]);
+        let mut iter = v.index_range(2..5).iter_indexed();
+        assert_eq!(iter.next_back(), Some((2, &4)));
+        assert_eq!(iter.next(), Some((0, &2)));
+        for (i, x) in v.index_range_mut(1..4).iter_indexed_mut() {
+            *x = i * 100;
+        }
+        assert_eq!(v, vec![0, 0, 100, 200, 4]);
+    }
+
+    #[test]
+    fn nested_slicing() {
+        let mut v: VecDeque<usize> = (0..12).collect();
+        {
+            let outer = v.index_range(2..10);
+            let inner = outer.index_range(1..3);
+            assert_eq!(inner, [3, 4]);
+            assert_eq!(outer.index_range_from(6..).index_range_to(..1), [8]);
+            assert_eq!(outer.try_index_range(7..9), Err(OutOfBounds { range: 7..9, len: 8 }));
+        }
+        let mut outer = v.index_range_mut(2..10);
+        outer.index_range_mut(1..3).fill(0);
+        let (mut a, mut b) = outer.index_range_mut(4..8).split_at_mut(2);
+        a.swap_with_slice(&mut b);
+        assert_eq!(v.index_range(2..10), [2, 0, 0, 5, 8, 9, 6, 7]);
+    }
+
+    #[test]
+    fn subslices() {
+        let mut v: VecDeque<usize> = (0..12).collect();
+        {
+            let outer = v.index_range(2..10);
+            assert_eq!(outer.subslice(1..3), [3, 4]);
+            assert_eq!(outer.subslice_from(6..), [8, 9]);
+            assert_eq!(outer.subslice_to(..2).subslice_from(1..), [3]);
+        }
+        let mut outer = v.index_range_mut(2..10);
+        outer.subslice_mut(2..4).fill(0);
+        outer.subslice_from_mut(7..)[0] = 1;
+        outer.subslice_to_mut(..1).reverse();
+        assert_eq!(v.index_range(2..10), [2, 3, 0, 0, 6, 7, 8, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn subslice_out_of_bounds() {
+        let v = test_vec();
+        v.index_range(1..4).subslice(2..4);
+    }
+
+    #[test]
+    fn reborrowing() {
+        fn bump(s: SliceMut<VecDeque<usize>, usize, usize>) {
+            for x in s {
+                *x += 10;
+            }
+        }
+        let mut v = test_vec();
+        let mut s = v.index_range_mut(1..4);
+        bump(s.reborrow());
+        bump(s.reborrow().subslice_mut(1..2).reborrow());
+        assert_eq!(s.as_slice(), [11, 22, 13]);
+        assert_eq!(s.as_slice().subslice(1..3), [22, 13]);
+    }
+
+    #[test]
+    fn split_first_and_last() {
+        fn sum(s: ::Slice<VecDeque<usize>, usize, usize>) -> usize {
+            match s.split_first() {
+                Some((first, rest)) => first + sum(rest),
+                None => 0,
+            }
+        }
+        let mut v = test_vec();
+        assert_eq!(sum(v.index_range(1..4)), 6);
+        let (last, rest) = v.index_range(1..4).split_last().unwrap();
+        assert_eq!((*last, rest), (3, v.index_range(1..3)));
+        assert!(v.index_range(2..2).split_last().is_none());
+
+        let (first, rest) = v.index_range_mut(1..4).split_first_mut().unwrap();
+        let (last, mut middle) = rest.split_last_mut().unwrap();
+        std::mem::swap(first, last);
+        middle[0] = 7;
+        assert_eq!(v, [0, 3, 7, 1, 4]);
+        assert!(v.index_range_mut(0..0).split_first_mut().is_none());
+    }
+
+    #[test]
+    fn disjoint_ranges() {
+        let mut v: VecDeque<usize> = (0..10).collect();
+        {
+            let s = v.index_range_mut(1..9);
+            let [mut a, b, mut c] = s.get_disjoint_mut([4..6, 2..2, 0..3]).unwrap();
+            a.fill(0);
+            c.reverse();
+            assert!(b.is_empty());
+        }
+        assert!(v.index_range_mut(1..9).get_disjoint_mut([0..3, 2..4]).is_none());
+        assert!(v.index_range_mut(1..9).get_disjoint_mut([0..1, 7..9]).is_none());
+        assert_eq!(v, [0, 3, 2, 1, 4, 0, 0, 7, 8, 9]);
+    }
+
+    #[test]
+    fn unchecked_access() {
+        let mut v = test_vec();
+        let s = unsafe { ::Slice::new_unchecked(&v, 1, 3) };
+        assert_eq!(s, [1, 2, 3]);
+        assert_eq!(unsafe { *s.get_unchecked(2) }, 3);
+        let mut s = unsafe { SliceMut::new_unchecked(&mut v, 2, 2) };
+        unsafe {
+            *s.get_unchecked_mut(1) += *s.get_unchecked(0);
+        }
+        assert_eq!(v, [0, 1, 2, 5, 4]);
+    }
+
+    #[test]
+    fn clamped_slicing() {
+        let mut v = test_vec();
+        assert_eq!(v.index_range_clamped(2..9), [2, 3, 4]);
+        assert_eq!(v.index_range_clamped(..=1), [0, 1]);
+        assert!(v.index_range_clamped(7..).is_empty());
+        assert!(v.index_range_clamped(6..8).is_empty());
+        v.index_range_clamped_mut(3..100).fill(0);
+        assert_eq!(v, [0, 1, 2, 0, 0]);
+    }
+
+    struct SignedIdx(VecDeque<usize>);
+
+    impl Index<i32> for SignedIdx {
+        type Output = usize;
+        fn index(&self, index: i32) -> &usize {
+            &self.0[index as usize]
+        }
+    }
+
+    impl TakeSlice<usize, i32> for SignedIdx {
+        fn len(&self) -> i32 {
+            self.0.len() as i32
+        }
+    }
+
+    #[test]
+    fn negative_indexing() {
+        let s = SignedIdx(test_vec());
+        assert_eq!(s.index_range_signed(-3..), [2, 3, 4]);
+        let last = -1;
+        assert_eq!(s.index_range_signed(1..last), [1, 2, 3]);
+        assert_eq!(s.index_range_signed(-2..=-2), [3]);
+        assert_eq!(s.index_range_signed(..2), [0, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_indexing_out_of_bounds() {
+        SignedIdx(test_vec()).index_range_signed(-6..);
+    }
+
+    struct ByteIdx(VecDeque<usize>);
+
+    impl Index<u8> for ByteIdx {
+        type Output = usize;
+        fn index(&self, index: u8) -> &usize {
+            &self.0[index as usize]
+        }
+    }
+
+    impl TakeSlice<usize, u8> for ByteIdx {
+        fn len(&self) -> u8 {
+            self.0.len() as u8
+        }
+    }
+
+    #[test]
+    fn bounds_at_index_max() {
+        use std::ops::Bound;
+        let s = ByteIdx(test_vec());
+        assert_eq!(s.try_take_slice(..=u8::MAX).unwrap_err(),
+                   OutOfBounds { range: 0..u8::MAX, len: 5 });
+        assert!(s.try_take_slice((Bound::Excluded(u8::MAX), Bound::Unbounded)).is_err());
+        assert_eq!(s.index_range_clamped(2..=u8::MAX), [2, 3, 4]);
+        assert!(s.index_range_clamped((Bound::Excluded(u8::MAX), Bound::Unbounded)).is_empty());
+        assert_eq!(u8::MAX.checked_successor(), None);
+        assert_eq!((-1i8).checked_successor(), Some(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Range out of bounds")]
+    fn inclusive_index_max() {
+        ByteIdx(test_vec()).take_slice(..=u8::MAX);
+    }
+
+    struct Unbounded;
+
+    impl Index<u128> for Unbounded {
+        type Output = ();
+        fn index(&self, _: u128) -> &() {
+            &()
+        }
+    }
+
+    impl TakeSlice<(), u128> for Unbounded {
+        fn len(&self) -> u128 {
+            u128::MAX
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Length doesn't fit in a usize")]
+    fn iter_longer_than_usize() {
+        unsafe { ::Slice::new_unchecked(&Unbounded, 0, u128::MAX) }.iter();
+    }
+
     #[test]
     #[should_panic]
     fn out_of_bounds_check() {
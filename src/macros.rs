@@ -0,0 +1,142 @@
+/// Takes a `Slice` of a container using the same syntax as slicing a built-in
+/// slice, expanding to the matching `TakeSlice::index_range*` method.
+///
+/// ```
+/// #[macro_use]
+/// extern crate owned_slice;
+///
+/// use std::collections::VecDeque;
+///
+/// # fn main() {
+/// let v: VecDeque<u32> = (0..5).collect();
+/// assert_eq!(slice!(v[1..3]), [1, 2]);
+/// assert_eq!(slice!(v[..2]), [0, 1]);
+/// assert_eq!(slice!(v[3..]), [3, 4]);
+/// assert_eq!(slice!(v[1..=2]), [1, 2]);
+/// assert_eq!(slice!(v[..]).len(), 5);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! slice {
+    (@split [$($c:tt)+] [$($r:tt)*]) => {
+        slice!(@range [$($c)+] [] $($r)*)
+    };
+    (@split [$($c:tt)*] $head:tt $($rest:tt)+) => {
+        slice!(@split [$($c)* $head] $($rest)+)
+    };
+    (@range [$($c:tt)+] []) => {
+        compile_error!("expected a range, like `container[a..b]`")
+    };
+    (@range [$($c:tt)+] [] ..) => {
+        { use $crate::TakeSlice; ($($c)+).index_range_full(..) }
+    };
+    (@range [$($c:tt)+] [] ..= $($e:tt)+) => {
+        { use $crate::TakeSlice; ($($c)+).index_range_to_inclusive(..= $($e)+) }
+    };
+    (@range [$($c:tt)+] [] .. $($e:tt)+) => {
+        { use $crate::TakeSlice; ($($c)+).index_range_to(.. $($e)+) }
+    };
+    (@range [$($c:tt)+] [$($s:tt)+] ..= $($e:tt)+) => {
+        { use $crate::TakeSlice; ($($c)+).index_range_inclusive(($($s)+) ..= ($($e)+)) }
+    };
+    (@range [$($c:tt)+] [$($s:tt)+] .. $($e:tt)+) => {
+        { use $crate::TakeSlice; ($($c)+).index_range(($($s)+) .. ($($e)+)) }
+    };
+    (@range [$($c:tt)+] [$($s:tt)+] ..) => {
+        { use $crate::TakeSlice; ($($c)+).index_range_from(($($s)+) ..) }
+    };
+    (@range [$($c:tt)+] [$($s:tt)*] $head:tt $($rest:tt)*) => {
+        slice!(@range [$($c)+] [$($s)* $head] $($rest)*)
+    };
+    ($($t:tt)+) => {
+        slice!(@split [] $($t)+)
+    };
+}
+
+/// Like `slice!`, but takes a `SliceMut`
+/// using the `TakeSlice::index_range*_mut` methods.
+///
+/// ```
+/// #[macro_use]
+/// extern crate owned_slice;
+///
+/// use std::collections::VecDeque;
+///
+/// # fn main() {
+/// let mut v: VecDeque<u32> = (0..5).collect();
+/// slice_mut!(v[1..4]).reverse();
+/// slice_mut!(v[3..])[0] = 9;
+/// assert_eq!(v, [0, 3, 2, 9, 4]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! slice_mut {
+    (@split [$($c:tt)+] [$($r:tt)*]) => {
+        slice_mut!(@range [$($c)+] [] $($r)*)
+    };
+    (@split [$($c:tt)*] $head:tt $($rest:tt)+) => {
+        slice_mut!(@split [$($c)* $head] $($rest)+)
+    };
+    (@range [$($c:tt)+] []) => {
+        compile_error!("expected a range, like `container[a..b]`")
+    };
+    (@range [$($c:tt)+] [] ..) => {
+        { use $crate::TakeSlice; ($($c)+).index_range_full_mut(..) }
+    };
+    (@range [$($c:tt)+] [] ..= $($e:tt)+) => {
+        { use $crate::TakeSlice; ($($c)+).index_range_to_inclusive_mut(..= $($e)+) }
+    };
+    (@range [$($c:tt)+] [] .. $($e:tt)+) => {
+        { use $crate::TakeSlice; ($($c)+).index_range_to_mut(.. $($e)+) }
+    };
+    (@range [$($c:tt)+] [$($s:tt)+] ..= $($e:tt)+) => {
+        { use $crate::TakeSlice; ($($c)+).index_range_inclusive_mut(($($s)+) ..= ($($e)+)) }
+    };
+    (@range [$($c:tt)+] [$($s:tt)+] .. $($e:tt)+) => {
+        { use $crate::TakeSlice; ($($c)+).index_range_mut(($($s)+) .. ($($e)+)) }
+    };
+    (@range [$($c:tt)+] [$($s:tt)+] ..) => {
+        { use $crate::TakeSlice; ($($c)+).index_range_from_mut(($($s)+) ..) }
+    };
+    (@range [$($c:tt)+] [$($s:tt)*] $head:tt $($rest:tt)*) => {
+        slice_mut!(@range [$($c)+] [$($s)* $head] $($rest)*)
+    };
+    ($($t:tt)+) => {
+        slice_mut!(@split [] $($t)+)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    struct Wrapper {
+        inner: VecDeque<u32>,
+    }
+
+    fn test_vec() -> VecDeque<u32> {
+        (0..10).collect()
+    }
+
+    #[test]
+    fn range_syntax() {
+        let v = test_vec();
+        let (a, b) = (2, 5);
+        assert_eq!(slice!(v[a..b]), [2, 3, 4]);
+        assert_eq!(slice!(v[a + 1..b * 2 - 2]), [3, 4, 5, 6, 7]);
+        assert_eq!(slice!(v[..=1]), [0, 1]);
+        assert_eq!(slice!(v[8..]), [8, 9]);
+        let w = Wrapper { inner: test_vec() };
+        assert_eq!(slice!(w.inner[7..=8]), [7, 8]);
+        assert_eq!(slice!(w.inner[..]).len(), 10);
+    }
+
+    #[test]
+    fn mutable() {
+        let mut w = Wrapper { inner: test_vec() };
+        slice_mut!(w.inner[..3]).fill(0);
+        slice_mut!(w.inner[8..]).reverse();
+        slice_mut!(w.inner[4..=5]).swap(0, 1);
+        assert_eq!(slice!(w.inner[..]), [0, 0, 0, 3, 5, 4, 6, 7, 9, 8]);
+    }
+}
@@ -0,0 +1,201 @@
+use core::fmt::{self, Debug};
+use core::iter;
+use core::ops::{Index, Range};
+use num_traits::Zero;
+use {BoundsPolicy, Chunks, Idx, Iter, Slice, Windows};
+
+/// A read-only view which applies a function to each element of a slice
+/// as it is accessed, created with `Slice::map_view`.
+///
+/// Nothing is computed up front. The view can be narrowed with `subslice`
+/// or split with `chunks` and `windows` just like the slice underneath it.
+///
+/// ```
+/// use owned_slice::TakeSlice;
+///
+/// let samples = [(3.0f64, 4.0f64), (0.0, 1.0), (6.0, 8.0), (5.0, 12.0)];
+/// let magnitudes = samples.index_range_full(..).map_view(|&(re, im)| (re * re + im * im).sqrt());
+/// assert_eq!(magnitudes.get(0), Some(5.0));
+/// let peaks: Vec<f64> = magnitudes.chunks(2)
+///     .map(|chunk| chunk.iter().fold(0.0, f64::max))
+///     .collect();
+/// assert_eq!(peaks, [5.0, 13.0]);
+/// ```
+pub struct MapView<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a, F> {
+    slice: Slice<'a, K, I, T>,
+    f: F,
+}
+
+/// An iterator which wraps each slice produced by another iterator
+/// in a `MapView`, created with `MapView::chunks` and `MapView::windows`.
+pub struct MapViews<'f, S, F: 'f> {
+    inner: S,
+    f: &'f F,
+}
+
+impl<'a, K, I, T, P> Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Returns a view which lazily applies `f` to each element.
+    pub fn map_view<F, U>(self, f: F) -> MapView<'a, K, I, T, F>
+        where F: Fn(&T) -> U
+    {
+        MapView { slice: self.with_policy(), f }
+    }
+}
+
+impl<'a, K, I, T, F, U> MapView<'a, K, I, T, F>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          F: Fn(&T) -> U
+{
+    /// Returns the number of elements in the view.
+    #[inline]
+    pub fn len(&self) -> I {
+        self.slice.len()
+    }
+
+    /// Returns `true` if the view has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.slice.len() == Zero::zero()
+    }
+
+    /// Computes the element at `index`, or returns `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: I) -> Option<U> {
+        self.slice.get(index).map(&self.f)
+    }
+
+    pub fn iter(&self) -> iter::Map<Iter<'a, K, I, T>, &F> {
+        self.slice.iter().map(&self.f)
+    }
+
+    /// Returns a view of `range` within this view, sharing its function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds.
+    pub fn subslice(&self, range: Range<I>) -> MapView<'a, K, I, T, &F> {
+        self.slice.subslice(range).map_view(&self.f)
+    }
+
+    /// Returns an iterator over `size` elements of the view at a time,
+    /// as in `Slice::chunks`.
+    pub fn chunks(&self, size: I) -> MapViews<'_, Chunks<'a, K, I, T>, F> {
+        MapViews {
+            inner: self.slice.chunks(size),
+            f: &self.f,
+        }
+    }
+
+    /// Returns an iterator over all overlapping runs of `size` elements,
+    /// as in `Slice::windows`.
+    pub fn windows(&self, size: I) -> MapViews<'_, Windows<'a, K, I, T>, F> {
+        MapViews {
+            inner: self.slice.windows(size),
+            f: &self.f,
+        }
+    }
+
+    /// Returns the slice underneath the view.
+    pub fn into_inner(self) -> Slice<'a, K, I, T> {
+        self.slice
+    }
+}
+
+impl<'a, K, I, T, F> Clone for MapView<'a, K, I, T, F>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          F: Clone
+{
+    fn clone(&self) -> Self {
+        MapView {
+            slice: self.slice,
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<'a, K, I, T, F> Copy for MapView<'a, K, I, T, F>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          F: Copy
+{
+}
+
+impl<'a, 'f, S, K, I, T, F> Iterator for MapViews<'f, S, F>
+    where S: Iterator<Item = Slice<'a, K, I, T>>,
+          K: 'a + ?Sized + Index<I, Output = T>,
+          I: 'a + Idx,
+          T: 'a
+{
+    type Item = MapView<'a, K, I, T, &'f F>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let f = self.f;
+        self.inner.next().map(|slice| MapView { slice, f })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, 'f, S, K, I, T, F> DoubleEndedIterator for MapViews<'f, S, F>
+    where S: DoubleEndedIterator<Item = Slice<'a, K, I, T>>,
+          K: 'a + ?Sized + Index<I, Output = T>,
+          I: 'a + Idx,
+          T: 'a
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let f = self.f;
+        self.inner.next_back().map(|slice| MapView { slice, f })
+    }
+}
+
+impl<'a, 'f, S, K, I, T, F> ExactSizeIterator for MapViews<'f, S, F>
+    where S: ExactSizeIterator<Item = Slice<'a, K, I, T>>,
+          K: 'a + ?Sized + Index<I, Output = T>,
+          I: 'a + Idx,
+          T: 'a
+{
+}
+
+impl<'a, K, I, T, F, U> Debug for MapView<'a, K, I, T, F>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          F: Fn(&T) -> U,
+          U: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use TakeSlice;
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..10).collect()
+    }
+
+    #[test]
+    fn map_view() {
+        let v = test_vec();
+        let squares = v.index_range(1..6).map_view(|x| x * x);
+        assert_eq!(format!("{:?}", squares), "[1, 4, 9, 16, 25]");
+        assert_eq!(squares.get(2), Some(9));
+        assert_eq!(squares.get(5), None);
+        assert_eq!(squares.subslice(3..5).iter().collect::<Vec<_>>(), [16, 25]);
+        let sums: Vec<usize> = squares.windows(2).map(|w| w.iter().sum()).collect();
+        assert_eq!(sums, [5, 13, 25, 41]);
+        assert_eq!(squares.chunks(2).next_back().map(|c| c.len()), Some(1));
+        assert_eq!(squares.into_inner(), [1, 2, 3, 4, 5]);
+    }
+}
@@ -0,0 +1,248 @@
+use core::fmt::{self, Debug};
+use core::ops::Index;
+use core::slice;
+use num_traits::{Zero, One};
+use util::{to_usize, from_usize};
+use {BoundsPolicy, Idx, PermutedSlice, Slice, TakeSlice};
+
+/// An immutable view which hides the elements of a slice whose entry in a
+/// boolean mask is `false`, created with `Slice::masked`.
+///
+/// The container is left untouched, so logically deleted entries can be
+/// skipped without compacting it. Iteration is linear, but random access
+/// has to scan the mask and takes O(n) time; use `Slice::select` with an
+/// index list when fast random access matters.
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use owned_slice::TakeSlice;
+///
+/// let queue: VecDeque<u32> = vec![1, 2, 3, 4].into_iter().collect();
+/// let live = [true, false, true, true];
+/// let visible = queue.index_range_full(..).masked(&live[..]);
+/// assert_eq!(visible.len(), 3);
+/// assert_eq!(visible[1], 3);
+/// assert_eq!(visible.iter().sum::<u32>(), 8);
+/// ```
+pub struct MaskedSlice<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a, M> {
+    slice: Slice<'a, K, I, T>,
+    mask: M,
+    len: I,
+}
+
+/// The iterator over the retained elements of a `MaskedSlice`.
+pub struct MaskedIter<'a, 'm, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a> {
+    slice: Slice<'a, K, I, T>,
+    mask: slice::Iter<'m, bool>,
+    cur: I,
+    remaining: usize,
+}
+
+impl<'a, K, I, T, P> Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Returns a view of the elements whose entry in `mask` is `true`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mask` is not the same length as the slice.
+    pub fn masked<M>(self, mask: M) -> MaskedSlice<'a, K, I, T, M>
+        where M: AsRef<[bool]>
+    {
+        let bits = mask.as_ref();
+        if bits.len() != to_usize(self.len()) {
+            panic!("mask has length {} but the slice has length {:?}", bits.len(), self.len());
+        }
+        let len = from_usize(bits.iter().filter(|&&keep| keep).count());
+        MaskedSlice { slice: self.with_policy(), mask, len }
+    }
+
+    /// Returns a view of the elements at `indices`, which must be strictly
+    /// increasing, giving O(1) random access to the retained elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `indices` is not strictly increasing or any index is out of bounds.
+    pub fn select<O>(self, indices: O) -> PermutedSlice<'a, K, I, T, O>
+        where O: AsRef<[I]>
+    {
+        if indices.as_ref().windows(2).any(|pair| pair[0] >= pair[1]) {
+            panic!("selected indices must be strictly increasing");
+        }
+        self.permuted(indices)
+    }
+}
+
+impl<'a, K, I, T, M> MaskedSlice<'a, K, I, T, M>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          M: AsRef<[bool]>
+{
+    /// Returns the number of retained elements.
+    #[inline]
+    pub fn len(&self) -> I {
+        self.len
+    }
+
+    /// Returns `true` if no elements are retained.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == Zero::zero()
+    }
+
+    /// Returns a reference to the `index`th retained element,
+    /// or `None` if it is out of bounds.
+    pub fn get(&self, index: I) -> Option<&'a T> {
+        if index < Zero::zero() || index >= self.len {
+            return None;
+        }
+        self.iter().nth(to_usize(index))
+    }
+
+    pub fn iter(&self) -> MaskedIter<'a, '_, K, I, T> {
+        MaskedIter {
+            slice: self.slice,
+            mask: self.mask.as_ref().iter(),
+            cur: Zero::zero(),
+            remaining: to_usize(self.len),
+        }
+    }
+
+    /// Returns the mask.
+    pub fn mask(&self) -> &[bool] {
+        self.mask.as_ref()
+    }
+
+    /// Splits the view back into the original slice and the mask.
+    pub fn into_parts(self) -> (Slice<'a, K, I, T>, M) {
+        (self.slice, self.mask)
+    }
+}
+
+impl<'a, 'm, K, I, T> Iterator for MaskedIter<'a, 'm, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for &keep in &mut self.mask {
+            let index = self.cur;
+            self.cur = index + One::one();
+            if keep {
+                self.remaining -= 1;
+                return self.slice.get(index);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, 'm, K, I, T> ExactSizeIterator for MaskedIter<'a, 'm, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+}
+
+impl<'a, K, I, T, M> Clone for MaskedSlice<'a, K, I, T, M>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          M: Clone
+{
+    fn clone(&self) -> Self {
+        MaskedSlice {
+            slice: self.slice,
+            mask: self.mask.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, K, I, T, M> Copy for MaskedSlice<'a, K, I, T, M>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          M: Copy
+{
+}
+
+impl<'a, K, I, T, M> Index<I> for MaskedSlice<'a, K, I, T, M>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          M: AsRef<[bool]>
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: I) -> &T {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, self.len),
+        }
+    }
+}
+
+impl<'a, K, I, T, M> TakeSlice<T, I> for MaskedSlice<'a, K, I, T, M>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          M: AsRef<[bool]>
+{
+    fn len(&self) -> I {
+        self.len
+    }
+}
+
+impl<'a, K, I, T, M> Debug for MaskedSlice<'a, K, I, T, M>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          T: Debug,
+          M: AsRef<[bool]>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use TakeSlice;
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..10).collect()
+    }
+
+    #[test]
+    fn masked() {
+        let v = test_vec();
+        let mask: Vec<bool> = (0..5).map(|i| i % 2 == 0).collect();
+        let m = v.index_range(5..10).masked(mask);
+        assert_eq!(format!("{:?}", m), "[5, 7, 9]");
+        assert_eq!(m.len(), 3);
+        assert_eq!(m.get(2), Some(&9));
+        assert_eq!(m.get(3), None);
+        assert_eq!(m.iter().len(), 3);
+        assert_eq!(m.index_range(1..3), [7, 9]);
+        assert!(v.index_range_to(..2).masked([false, false]).is_empty());
+    }
+
+    #[test]
+    fn select() {
+        let v = test_vec();
+        let s = v.index_range_full(..).select(vec![1, 4, 8]);
+        assert_eq!(format!("{:?}", s), "[1, 4, 8]");
+        assert_eq!(s[2], 8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn select_unordered() {
+        test_vec().index_range_full(..).select([4, 1]);
+    }
+}
@@ -0,0 +1,77 @@
+use core::ops::IndexMut;
+use num_traits::Zero;
+use {BoundsPolicy, Idx, SliceMut};
+
+impl<'a, K, I, T, P> SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Replaces each element with the sum of itself and every element before it.
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use owned_slice::TakeSlice;
+    ///
+    /// let mut sales: VecDeque<u32> = vec![3, 1, 4, 1, 5].into_iter().collect();
+    /// sales.index_range_mut(1..5).prefix_sum_in_place();
+    /// assert_eq!(sales, [3, 1, 5, 6, 11]);
+    /// ```
+    pub fn prefix_sum_in_place(&mut self)
+        where T: Clone + Zero
+    {
+        self.scan_in_place(T::zero(), |total, x| total.clone() + x.clone())
+    }
+
+    /// Replaces each element with the running result of combining it with the
+    /// result for the previous element, starting from `init`.
+    ///
+    /// Element `i` becomes `f(&acc, &self[i])`, where `acc` is the new
+    /// value of element `i - 1`, or `init` for the first element.
+    ///
+    /// ```
+    /// use owned_slice::TakeSlice;
+    ///
+    /// let mut peaks = vec![2, 5, 3, 7, 1];
+    /// peaks.index_range_full_mut(..).scan_in_place(0, |max, &x| if x > *max { x } else { *max });
+    /// assert_eq!(peaks, [2, 5, 5, 7, 7]);
+    /// ```
+    pub fn scan_in_place<F>(&mut self, init: T, mut f: F)
+        where F: FnMut(&T, &T) -> T,
+              T: Clone
+    {
+        let mut acc = init;
+        self.for_each_mut(|x| {
+            acc = f(&acc, x);
+            x.clone_from(&acc);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use TakeSlice;
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..10).collect()
+    }
+
+    #[test]
+    fn prefix_sum_in_place() {
+        let mut v = test_vec();
+        v.index_range_mut(2..6).prefix_sum_in_place();
+        assert_eq!(v.index_range_to(..7), [0, 1, 2, 5, 9, 14, 6]);
+        let mut floats = vec![0.5, 0.25, 0.25];
+        floats.index_range_full_mut(..).prefix_sum_in_place();
+        assert_eq!(floats, [0.5, 0.75, 1.0]);
+    }
+
+    #[test]
+    fn scan_in_place() {
+        let mut v = test_vec();
+        v.index_range_mut(1..5).scan_in_place(1, |product, x| product * x);
+        assert_eq!(v.index_range_to(..6), [0, 1, 2, 6, 24, 5]);
+        v.index_range_to_mut(..0).scan_in_place(7, |_, _| unreachable!());
+    }
+}
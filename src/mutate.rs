@@ -0,0 +1,673 @@
+use core::ops::{Index, IndexMut, RangeBounds};
+use core::mem;
+use core::ptr;
+use num_traits::{Zero, One};
+use super::{Idx, Slice, SliceMut, TakeSlice, UniqueIndexMut, BoundsPolicy};
+use util::{to_usize, from_usize, to_range, assert_in_bounds, index_unique, AbortOnUnwind};
+
+impl<'a, K, I, T, P> SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Fills the slice with clones of `value`.
+    pub fn fill(&mut self, value: T)
+        where T: Clone
+    {
+        self.fill_with(|| value.clone())
+    }
+
+    /// Fills the slice with values returned by calling `f` repeatedly.
+    pub fn fill_with<F>(&mut self, mut f: F)
+        where F: FnMut() -> T
+    {
+        self.fill_with_index(|_| f())
+    }
+
+    /// Fills the slice by calling `f` with the index of each element.
+    pub fn fill_with_index<F>(&mut self, mut f: F)
+        where F: FnMut(I) -> T
+    {
+        let start = self.start;
+        let mut i = Zero::zero();
+        while i != self.len {
+            self.list_mut()[start + i] = f(i);
+            i = i + One::one();
+        }
+    }
+
+    /// Calls `f` on each element in turn.
+    ///
+    /// This indexes the container directly rather than going through
+    /// `IterMut`, so it works for any `IndexMut` container.
+    #[inline]
+    pub fn for_each_mut<F>(&mut self, mut f: F)
+        where F: FnMut(&mut T)
+    {
+        let start = self.start;
+        let mut i = Zero::zero();
+        while i != self.len {
+            f(&mut self.list_mut()[start + i]);
+            i = i + One::one();
+        }
+    }
+
+    /// Replaces each element with the result of calling `f` on it.
+    #[inline]
+    pub fn map_in_place<F>(&mut self, mut f: F)
+        where F: FnMut(T) -> T,
+              T: Copy
+    {
+        self.for_each_mut(|x| *x = f(*x))
+    }
+
+    /// Replaces each element with the result of calling `f` on it,
+    /// for element types which aren't `Copy`.
+    ///
+    /// Each element is moved out with `mem::take`, leaving its default value
+    /// behind while `f` runs.
+    #[inline]
+    pub fn map_in_place_take<F>(&mut self, mut f: F)
+        where F: FnMut(T) -> T,
+              T: Default
+    {
+        self.for_each_mut(|x| *x = f(mem::take(x)))
+    }
+
+    /// Calls `f` on each element of the slice together with the element of
+    /// `src` at the same index, for example to accumulate `src` into the slice.
+    ///
+    /// `src` can be any `TakeSlice` container or view, including `[T]`
+    /// and `Slice`, and its elements don't need to be the same type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices have different lengths.
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use owned_slice::TakeSlice;
+    ///
+    /// let mut mix: VecDeque<f32> = vec![0.5, 0.5, 0.5].into_iter().collect();
+    /// let voice = [0.25, -0.25];
+    /// mix.index_range_mut(1..3).zip_apply(&voice[..], |dst, src| *dst += src);
+    /// assert_eq!(mix, [0.5, 0.75, 0.25]);
+    /// ```
+    pub fn zip_apply<S, U, I2, F>(&mut self, src: &S, mut f: F)
+        where S: ?Sized + TakeSlice<U, I2>,
+              I2: Idx,
+              F: FnMut(&mut T, &U)
+    {
+        assert_same_len(to_usize(src.len()), to_usize(self.len));
+        let start = self.start;
+        let mut i = Zero::zero();
+        let mut j = Zero::zero();
+        while i != self.len {
+            f(&mut self.list_mut()[start + i], &src[j]);
+            i = i + One::one();
+            j = j + One::one();
+        }
+    }
+
+    /// Copies all elements from `src` into the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices have different lengths.
+    pub fn copy_from_slice(&mut self, src: &[T])
+        where T: Copy
+    {
+        assert_same_len(src.len(), to_usize(self.len));
+        let mut i = Zero::zero();
+        for src in src {
+            self[i] = *src;
+            i = i + One::one();
+        }
+    }
+
+    /// Clones all elements from `src` into the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices have different lengths.
+    pub fn clone_from_slice(&mut self, src: &[T])
+        where T: Clone
+    {
+        assert_same_len(src.len(), to_usize(self.len));
+        let mut i = Zero::zero();
+        for src in src {
+            self[i].clone_from(src);
+            i = i + One::one();
+        }
+    }
+
+    /// Copies all elements from `src`, a slice over any container, into the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices have different lengths.
+    pub fn copy_from_view<K2, I2>(&mut self, src: &Slice<K2, I2, T>)
+        where K2: ?Sized + Index<I2, Output = T>,
+              I2: Idx,
+              T: Copy
+    {
+        assert_same_len(to_usize(src.len), to_usize(self.len));
+        let mut i = Zero::zero();
+        for src in src {
+            self[i] = *src;
+            i = i + One::one();
+        }
+    }
+
+    /// Clones all elements from `src`, a slice over any container, into the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices have different lengths.
+    pub fn clone_from_view<K2, I2>(&mut self, src: &Slice<K2, I2, T>)
+        where K2: ?Sized + Index<I2, Output = T>,
+              I2: Idx,
+              T: Clone
+    {
+        assert_same_len(to_usize(src.len), to_usize(self.len));
+        let mut i = Zero::zero();
+        for src in src {
+            self[i].clone_from(src);
+            i = i + One::one();
+        }
+    }
+
+    /// Swaps all elements in the slice with those in `other`,
+    /// which may be a slice over a different container.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices have different lengths.
+    pub fn swap_with_slice<K2, I2>(&mut self, other: &mut SliceMut<K2, I2, T>)
+        where K2: ?Sized + IndexMut<I2, Output = T>,
+              I2: Idx
+    {
+        if to_usize(self.len) != to_usize(other.len) {
+            panic!("destination and source slices have different lengths");
+        }
+        let mut i = Zero::zero();
+        let mut j = Zero::zero();
+        while i != self.len {
+            mem::swap(&mut self[i], &mut other[j]);
+            i = i + One::one();
+            j = j + One::one();
+        }
+    }
+
+    /// Swaps two elements in the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    pub fn swap(&mut self, a: I, b: I) {
+        if a >= self.len || b >= self.len {
+            panic!("Index out of bounds: {:?} or {:?} >= {:?}", a, b, self.len);
+        }
+        if a == b {
+            return;
+        }
+        let (a, b) = (self.start + a, self.start + b);
+        let list = self.list_mut();
+        // only one element is borrowed at a time, so this works for any
+        // `IndexMut` container. Until the element from `b` is written back
+        // to `a`, the one from `a` is in both places, so `index_mut`
+        // mustn't unwind in between.
+        unsafe {
+            let x = mem::ManuallyDrop::new(ptr::read(&list[a]));
+            let y = mem::replace(&mut list[b], mem::ManuallyDrop::into_inner(x));
+            let guard = AbortOnUnwind("index_mut panicked in the middle of a swap");
+            ptr::write(&mut list[a], y);
+            mem::forget(guard);
+        }
+    }
+
+    /// Reverses the order of elements in the slice, in place.
+    pub fn reverse(&mut self) {
+        let len = self.len;
+        self.reverse_range(Zero::zero(), len);
+    }
+
+    /// Rotates the slice in place so that the element at `mid` becomes
+    /// the first element, and the first `mid` elements move to the end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn rotate_left(&mut self, mid: I) {
+        let len = self.len;
+        assert_in_bounds(&(Zero::zero()..mid), len);
+        self.reverse_range(Zero::zero(), mid);
+        self.reverse_range(mid, len);
+        self.reverse_range(Zero::zero(), len);
+    }
+
+    /// Rotates the slice in place so that the last `k` elements move
+    /// to the front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k > len`.
+    pub fn rotate_right(&mut self, k: I) {
+        let len = self.len;
+        assert_in_bounds(&(Zero::zero()..k), len);
+        self.rotate_left(len - k);
+    }
+
+    /// Reverses the elements with indices in `lo..hi`.
+    pub(crate) fn reverse_range(&mut self, lo: I, hi: I) {
+        if lo >= hi {
+            return;
+        }
+        let mut a = lo;
+        let mut b = hi - One::one();
+        while a < b {
+            self.swap(a, b);
+            a = a + One::one();
+            b = b - One::one();
+        }
+    }
+}
+
+impl<'a, K, I, T, P> SliceMut<'a, K, I, T, P>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Borrows two distinct in-bounds elements at once.
+    fn pair_mut(&mut self, a: I, b: I) -> (&mut T, &mut T) {
+        debug_assert!(a != b && a < self.len && b < self.len);
+        let (list, start) = (self.list, self.start);
+        // `K: ?Sized + UniqueIndexMut` ensures that borrowing `b` didn't invalidate `a`,
+        // and that they don't overlap.
+        unsafe { (index_unique(list, start + a), index_unique(list, start + b)) }
+    }
+
+    /// Borrows several elements at once, or returns `None` if any index
+    /// is out of bounds or appears more than once.
+    pub fn get_many_mut<const N: usize>(&mut self, indices: [I; N]) -> Option<[&mut T; N]> {
+        for (n, index) in indices.iter().enumerate() {
+            if *index >= self.len || indices[..n].contains(index) {
+                return None;
+            }
+        }
+        let (list, start) = (self.list, self.start);
+        // The indices are distinct, so `K: UniqueIndexMut` ensures
+        // the references don't overlap.
+        Some(indices.map(|index| unsafe { index_unique(list, start + index) }))
+    }
+
+    /// Moves all but the first of consecutive equal elements to the end of
+    /// the slice, returning the number of unique elements which remain at
+    /// the front. The order of the removed elements is unspecified.
+    pub fn dedup_in_place(&mut self) -> I
+        where T: PartialEq
+    {
+        self.dedup_in_place_by(|a, b| a == b)
+    }
+
+    /// Like `dedup_in_place`, but considers consecutive elements the same
+    /// if they map to equal keys.
+    pub fn dedup_in_place_by_key<B, F>(&mut self, mut key: F) -> I
+        where B: PartialEq,
+              F: FnMut(&mut T) -> B
+    {
+        self.dedup_in_place_by(|a, b| key(a) == key(b))
+    }
+
+    /// Like `dedup_in_place`, but uses `same_bucket` to decide whether
+    /// two elements are the same. It is passed each element followed by
+    /// the last element that was kept.
+    pub fn dedup_in_place_by<F>(&mut self, mut same_bucket: F) -> I
+        where F: FnMut(&mut T, &mut T) -> bool
+    {
+        if self.len <= One::one() {
+            return self.len;
+        }
+        let mut write: I = One::one();
+        let mut read: I = One::one();
+        while read < self.len {
+            let duplicate = {
+                let (cur, prev) = self.pair_mut(read, write - One::one());
+                same_bucket(cur, prev)
+            };
+            if !duplicate {
+                self.swap(read, write);
+                write = write + One::one();
+            }
+            read = read + One::one();
+        }
+        write
+    }
+
+    /// Reorders the slice so that all elements satisfying `pred` come first,
+    /// returning the number of such elements. The relative order of elements
+    /// is not preserved.
+    pub fn partition_in_place<F>(&mut self, mut pred: F) -> I
+        where F: FnMut(&T) -> bool
+    {
+        let (mut lo, mut hi) = (Zero::zero(), self.len);
+        loop {
+            while lo < hi && pred(&self[lo]) {
+                lo = lo + One::one();
+            }
+            while lo < hi && !pred(&self[hi - One::one()]) {
+                hi = hi - One::one();
+            }
+            if lo >= hi {
+                return lo;
+            }
+            self.swap(lo, hi - One::one());
+            lo = lo + One::one();
+            hi = hi - One::one();
+        }
+    }
+
+    /// Reorders the slice so that all elements satisfying `pred` come first,
+    /// returning the number of such elements. The relative order of elements
+    /// within each group is preserved.
+    ///
+    /// This works in place without allocating, using `O(n log n)` swaps.
+    /// `pred` is called exactly once per element.
+    pub fn partition_stable<F>(&mut self, mut pred: F) -> I
+        where F: FnMut(&T) -> bool
+    {
+        let len = self.len;
+        self.partition_stable_range(Zero::zero(), len, &mut pred)
+    }
+
+    fn partition_stable_range<F>(&mut self, lo: I, hi: I, pred: &mut F) -> I
+        where F: FnMut(&T) -> bool
+    {
+        let len = to_usize(hi - lo);
+        if len == 0 {
+            return lo;
+        }
+        if len == 1 {
+            return if pred(&self[lo]) { hi } else { lo };
+        }
+        let mid = lo + from_usize(len / 2);
+        let left = self.partition_stable_range(lo, mid, pred);
+        let right = self.partition_stable_range(mid, hi, pred);
+        // rotate the rejected elements of the left half
+        // past the accepted elements of the right half
+        self.reverse_range(left, mid);
+        self.reverse_range(mid, right);
+        self.reverse_range(left, right);
+        left + (right - mid)
+    }
+}
+
+impl<'a, K, I, T, P> SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          T: Copy,
+          P: BoundsPolicy
+{
+    /// Copies the elements in `src` to the part of the slice starting at `dest`.
+    /// The two regions may overlap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either region extends past the end of the slice.
+    pub fn copy_within<R: RangeBounds<I>>(&mut self, src: R, dest: I) {
+        let src = to_range(&src, self.len);
+        assert_in_bounds(&src, self.len);
+        let count = src.end - src.start;
+        assert_in_bounds(&(dest..dest + count), self.len);
+        let start = self.start;
+        let list = self.list_mut();
+        if dest <= src.start {
+            // moving towards the front, so copy front to back
+            let mut i = Zero::zero();
+            while i != count {
+                list[start + dest + i] = list[start + src.start + i];
+                i = i + One::one();
+            }
+        } else {
+            // moving towards the back, so copy back to front
+            let mut i = count;
+            while i != Zero::zero() {
+                i = i - One::one();
+                list[start + dest + i] = list[start + src.start + i];
+            }
+        }
+    }
+}
+
+#[inline]
+fn assert_same_len(src: usize, dst: usize) {
+    if src != dst {
+        panic!("source slice length ({}) does not match destination slice length ({})",
+               src,
+               dst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use TakeSlice;
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..6).collect()
+    }
+
+    #[test]
+    fn fill() {
+        let mut v = test_vec();
+        v.index_range_mut(1..3).fill(9);
+        assert_eq!(v, vec![0, 9, 9, 3, 4, 5]);
+        let mut n = 0;
+        v.index_range_mut(3..6).fill_with(|| {
+            n += 1;
+            n
+        });
+        assert_eq!(v, vec![0, 9, 9, 1, 2, 3]);
+        v.index_range_mut(0..4).fill_with_index(|i| i * 10);
+        assert_eq!(v, vec![0, 10, 20, 30, 2, 3]);
+    }
+
+    #[test]
+    fn for_each_mut_and_map_in_place() {
+        let mut v = test_vec();
+        v.index_range_mut(1..4).for_each_mut(|x| *x += 10);
+        assert_eq!(v, vec![0, 11, 12, 13, 4, 5]);
+        v.index_range_mut(3..6).map_in_place(|x| x * 2);
+        assert_eq!(v, vec![0, 11, 12, 26, 8, 10]);
+        let mut strings: VecDeque<String> = vec!["a".to_string(), "b".to_string()].into_iter().collect();
+        strings.index_range_full_mut(..).map_in_place_take(|s| s + "!");
+        assert_eq!(strings, vec!["a!", "b!"]);
+    }
+
+    #[test]
+    fn zip_apply() {
+        let mut v = test_vec();
+        v.index_range_mut(0..3).zip_apply(&[10, 20, 30][..], |dst, src| *dst += src);
+        assert_eq!(v, vec![10, 21, 32, 3, 4, 5]);
+        let weights = vec![1.5f64, 2.0];
+        let src = weights.index_range_full(..);
+        v.index_range_mut(4..6).zip_apply(&src, |dst, w| *dst = (*dst as f64 * w) as usize);
+        assert_eq!(v, vec![10, 21, 32, 3, 6, 10]);
+    }
+
+    #[test]
+    fn copy_and_clone_from_slice() {
+        let mut v = test_vec();
+        v.index_range_mut(1..3).copy_from_slice(&[7, 8]);
+        assert_eq!(v, vec![0, 7, 8, 3, 4, 5]);
+        let mut strings: VecDeque<String> = vec!["a".to_string(), "b".to_string()].into_iter().collect();
+        strings.index_range_mut(0..2).clone_from_slice(&["x".to_string(), "y".to_string()]);
+        assert_eq!(strings, vec!["x", "y"]);
+    }
+
+    #[test]
+    fn copy_and_clone_from_view() {
+        let mut v = test_vec();
+        let src = vec![10, 11, 12, 13];
+        v.index_range_mut(0..2).copy_from_view(&::Slice::new(&src, 2..4));
+        assert_eq!(v, vec![12, 13, 2, 3, 4, 5]);
+        v.index_range_mut(4..6).clone_from_view(&::Slice::new(&src, 0..2));
+        assert_eq!(v, vec![12, 13, 2, 3, 10, 11]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn copy_from_slice_length_mismatch() {
+        let mut v = test_vec();
+        v.index_range_mut(1..3).copy_from_slice(&[7, 8, 9]);
+    }
+
+    #[test]
+    fn copy_within() {
+        let mut v = test_vec();
+        v.index_range_mut(1..6).copy_within(2.., 0);
+        assert_eq!(v, vec![0, 3, 4, 5, 4, 5]);
+        let mut v = test_vec();
+        v.index_range_mut(0..6).copy_within(0..4, 2);
+        assert_eq!(v, vec![0, 1, 0, 1, 2, 3]);
+        let mut v = test_vec();
+        v.index_range_mut(0..6).copy_within(1..=1, 1);
+        assert_eq!(v, test_vec());
+    }
+
+    #[test]
+    #[should_panic]
+    fn copy_within_out_of_bounds() {
+        let mut v = test_vec();
+        v.index_range_mut(0..4).copy_within(0..3, 2);
+    }
+
+    #[test]
+    fn get_many_mut() {
+        let mut v = test_vec();
+        let mut s = v.index_range_mut(1..5);
+        {
+            let [a, b, c] = s.get_many_mut([3, 0, 1]).unwrap();
+            *a += *b + *c;
+            *b = 0;
+        }
+        assert!(s.get_many_mut([1, 2, 1]).is_none());
+        assert!(s.get_many_mut([4]).is_none());
+        assert_eq!(s.get_many_mut([]), Some([]));
+        assert_eq!(v, [0, 0, 2, 3, 7, 5]);
+    }
+
+    #[test]
+    fn swap() {
+        let mut v: VecDeque<String> = vec!["a", "b", "c", "d"].into_iter().map(String::from).collect();
+        {
+            let mut s = v.index_range_mut(1..4);
+            s.swap(0, 2);
+            s.swap(1, 1);
+        }
+        assert_eq!(v, vec!["a", "d", "c", "b"]);
+        let mut v: Vec<String> = vec!["a", "b", "c"].into_iter().map(String::from).collect();
+        v.index_range_mut(0..3).swap(0, 1);
+        assert_eq!(v, vec!["b", "a", "c"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn swap_out_of_bounds() {
+        let mut v = test_vec();
+        v.index_range_mut(1..4).swap(0, 3);
+    }
+
+    #[test]
+    fn swap_with_slice() {
+        let mut v = test_vec();
+        let mut other = vec![10, 11, 12];
+        v.index_range_mut(0..2).swap_with_slice(&mut ::SliceMut::new(&mut other, 1..3));
+        assert_eq!(v, vec![11, 12, 2, 3, 4, 5]);
+        assert_eq!(other, vec![10, 0, 1]);
+        {
+            let (mut a, mut b) = v.index_range_mut(0..6).split_at_mut(3);
+            a.swap_with_slice(&mut b);
+        }
+        assert_eq!(v, vec![3, 4, 5, 11, 12, 2]);
+    }
+
+    #[test]
+    fn reverse() {
+        let mut v = test_vec();
+        v.index_range_mut(1..5).reverse();
+        assert_eq!(v, vec![0, 4, 3, 2, 1, 5]);
+        v.index_range_mut(0..5).reverse();
+        assert_eq!(v, vec![1, 2, 3, 4, 0, 5]);
+        v.index_range_mut(2..2).reverse();
+        v.index_range_mut(2..3).reverse();
+        assert_eq!(v, vec![1, 2, 3, 4, 0, 5]);
+        let mut array = [0, 1, 2, 3];
+        array.index_range_mut(1..4).reverse();
+        assert_eq!(array, [0, 3, 2, 1]);
+    }
+
+    #[test]
+    fn rotate() {
+        let mut v = test_vec();
+        v.index_range_mut(1..6).rotate_left(2);
+        assert_eq!(v, vec![0, 3, 4, 5, 1, 2]);
+        v.index_range_mut(1..6).rotate_right(2);
+        assert_eq!(v, test_vec());
+        v.index_range_mut(0..6).rotate_left(0);
+        v.index_range_mut(0..6).rotate_right(6);
+        assert_eq!(v, test_vec());
+        let mut vec = vec![0, 1, 2, 3, 4];
+        vec.index_range_mut(0..5).rotate_left(1);
+        vec.index_range_mut(1..5).rotate_right(1);
+        assert_eq!(vec, [1, 0, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rotate_out_of_bounds() {
+        let mut v = test_vec();
+        v.index_range_mut(0..3).rotate_left(4);
+    }
+
+    #[test]
+    fn partition_in_place() {
+        let mut v: VecDeque<usize> = (0..10).collect();
+        let n = v.index_range_mut(1..9).partition_in_place(|x| x % 3 == 0);
+        assert_eq!(n, 2);
+        assert!(v.index_range(1..3).iter().all(|x| x % 3 == 0));
+        assert!(v.index_range(3..9).iter().all(|x| x % 3 != 0));
+        assert_eq!((v[0], v[9]), (0, 9));
+        assert_eq!(v.index_range_mut(0..0).partition_in_place(|_| true), 0);
+        assert_eq!(v.index_range_mut(0..10).partition_in_place(|_| true), 10);
+    }
+
+    #[test]
+    fn partition_stable() {
+        let mut v: VecDeque<usize> = (0..10).collect();
+        let n = v.index_range_mut(1..9).partition_stable(|x| x % 3 == 0);
+        assert_eq!(n, 2);
+        assert_eq!(v, vec![0, 3, 6, 1, 2, 4, 5, 7, 8, 9]);
+        let mut calls = 0;
+        v.index_range_mut(0..10).partition_stable(|x| {
+            calls += 1;
+            x % 2 == 1
+        });
+        assert_eq!(calls, 10);
+        assert_eq!(v, vec![3, 1, 5, 7, 9, 0, 6, 2, 4, 8]);
+    }
+
+    #[test]
+    fn dedup_in_place() {
+        let mut v: VecDeque<usize> = vec![9, 1, 1, 2, 2, 2, 3, 1, 9].into_iter().collect();
+        let n = v.index_range_mut(1..8).dedup_in_place();
+        assert_eq!(n, 4);
+        assert_eq!(v.index_range(1..5).iter().cloned().collect::<Vec<_>>(), vec![1, 2, 3, 1]);
+        assert_eq!((v[0], v[8]), (9, 9));
+        let mut v: VecDeque<usize> = vec![10, 11, 20, 25, 31].into_iter().collect();
+        assert_eq!(v.index_range_full_mut(..).dedup_in_place_by_key(|x| *x / 10), 3);
+        assert_eq!(v.index_range(0..3).iter().cloned().collect::<Vec<_>>(), vec![10, 20, 31]);
+        assert_eq!(v.index_range_mut(0..1).dedup_in_place(), 1);
+        assert_eq!(v.index_range_mut(0..0).dedup_in_place(), 0);
+    }
+}
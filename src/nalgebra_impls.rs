@@ -0,0 +1,57 @@
+use nalgebra::{Dim, Dyn, Matrix, RawStorage, U1, VecStorage, ViewStorageMut};
+use super::{TakeSlice, UniqueIndexMut};
+
+/// Slices column vectors, including `DVector`, `SVector` and `OVector`
+/// as well as vector views.
+///
+/// Only `DVector`s and mutable views are `UniqueIndexMut`, as the elements
+/// of an `SVector` are stored inline. Take a view of an `SVector` (e.g. with
+/// `rows_mut`) to split or iterate it mutably.
+impl<T, D, S> TakeSlice<T, usize> for Matrix<T, D, U1, S>
+    where D: Dim,
+          S: RawStorage<T, D, U1>
+{
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+unsafe impl<T> UniqueIndexMut<usize> for Matrix<T, Dyn, U1, VecStorage<T, Dyn, U1>> {}
+
+unsafe impl<'b, T, D, RStride, CStride> UniqueIndexMut<usize> for Matrix<T, D, U1, ViewStorageMut<'b, T, D, U1, RStride, CStride>>
+    where D: Dim,
+          RStride: Dim,
+          CStride: Dim
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{DVector, Vector6};
+    use TakeSlice;
+
+    #[test]
+    fn state_vector_segments() {
+        // position followed by velocity
+        let mut state = Vector6::new(1.0, 2.0, 3.0, 0.5, 0.0, -0.5);
+        {
+            let mut view = state.rows_mut(0, 6);
+            let (position, velocity) = view.index_range_full_mut(..).split_at_mut(3);
+            for (p, v) in position.iter_mut().zip(velocity.iter()) {
+                *p += v * 2.0;
+            }
+        }
+        assert_eq!(state.index_range(0..3), [2.0, 2.0, 2.0]);
+        assert_eq!(state.index_range(3..6), [0.5, 0.0, -0.5]);
+    }
+
+    #[test]
+    fn dynamic_vectors() {
+        let mut v = DVector::from_vec(vec![3, 1, 2, 5, 4]);
+        v.index_range_mut(1..5).sort();
+        assert_eq!(v, DVector::from_vec(vec![3, 1, 2, 4, 5]));
+        let view = v.rows(2, 3);
+        assert_eq!(view.index_range(0..2), [2, 4]);
+        assert!(v.try_index_range(4..6).is_err());
+    }
+}
@@ -0,0 +1,41 @@
+use ndarray::{Array1, ArrayBase, ArrayViewMut1, Data, Ix1};
+use super::{TakeSlice, UniqueIndexMut};
+
+impl<S, A> TakeSlice<A, usize> for ArrayBase<S, Ix1>
+    where S: Data<Elem = A>
+{
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+// Only for owned arrays and mutable views: `ArcArray` may copy its
+// data on write, so `index_mut` could move elements borrowed earlier.
+unsafe impl<A> UniqueIndexMut<usize> for Array1<A> {}
+
+unsafe impl<'a, A> UniqueIndexMut<usize> for ArrayViewMut1<'a, A> {}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::{arr1, s, Array1};
+    use TakeSlice;
+
+    #[test]
+    fn arrays_and_views() {
+        let a: Array1<f64> = Array1::range(0., 10., 1.);
+        assert_eq!(a.index_range(2..5), [2., 3., 4.]);
+        let view = a.slice(s![..;2]);
+        assert_eq!(view.index_range(1..3), [2., 4.]);
+        assert_eq!(view.index_range_full(..).windows(2).count(), 4);
+    }
+
+    #[test]
+    fn slice_mut_writes_through() {
+        let mut a = arr1(&[5, 1, 4, 2, 3, 0]);
+        a.index_range_mut(1..5).sort();
+        assert_eq!(a, arr1(&[5, 1, 2, 3, 4, 0]));
+        let mut view = a.slice_mut(s![3..]);
+        view.index_range_mut(0..3).fill(9);
+        assert_eq!(a, arr1(&[5, 1, 2, 9, 9, 9]));
+    }
+}
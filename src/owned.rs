@@ -0,0 +1,261 @@
+use core::fmt::{self, Debug};
+use core::marker;
+use core::ops::{Index, IndexMut, Range};
+use num_traits::Zero;
+use util::assert_in_bounds;
+use {Idx, Iter, IterMut, Slice, SliceMut, TakeSlice, UniqueIndexMut};
+
+/// A slice which owns the container it views.
+///
+/// Created with `TakeSlice::into_slice`, this can be returned from functions
+/// without borrowing from anything, and the container can be recovered
+/// again with `into_inner`.
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use owned_slice::{OwnedSlice, TakeSlice};
+///
+/// fn tail(n: usize) -> OwnedSlice<VecDeque<usize>, usize, usize> {
+///     let deque: VecDeque<usize> = (0..n).collect();
+///     deque.into_slice(n / 2..n)
+/// }
+///
+/// let tail = tail(6);
+/// assert_eq!(tail.as_slice(), [3, 4, 5]);
+/// assert_eq!(tail.into_inner().len(), 6);
+/// ```
+#[derive(Clone)]
+pub struct OwnedSlice<K: Index<I, Output = T>, I: Idx, T> {
+    list: K,
+    start: I,
+    len: I,
+    ty: marker::PhantomData<T>,
+}
+
+impl<K, I, T> OwnedSlice<K, I, T>
+    where K: Index<I, Output = T>,
+          I: Idx
+{
+    pub fn new(list: K, index: Range<I>) -> OwnedSlice<K, I, T> {
+        OwnedSlice {
+            list,
+            start: index.start,
+            len: index.end - index.start,
+            ty: marker::PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the slice.
+    #[inline]
+    pub fn len(&self) -> I {
+        self.len
+    }
+
+    /// Returns `true` if the slice has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == Zero::zero()
+    }
+
+    /// Returns the range of the container covered by the slice.
+    #[inline]
+    pub fn range(&self) -> Range<I> {
+        self.start..self.start + self.len
+    }
+
+    /// Returns a reference to the element at `index`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: I) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    /// Returns the first element of the slice, or `None` if it is empty.
+    #[inline]
+    pub fn first(&self) -> Option<&T> {
+        self.get(Zero::zero())
+    }
+
+    /// Returns the last element of the slice, or `None` if it is empty.
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        self.as_slice().last()
+    }
+
+    /// Narrows the slice to `range`, which is relative to this slice
+    /// rather than to the underlying container.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of the bounds of this slice.
+    pub fn subslice(self, range: Range<I>) -> OwnedSlice<K, I, T> {
+        assert_in_bounds(&range, self.len);
+        let start = self.start;
+        OwnedSlice::new(self.list, start + range.start..start + range.end)
+    }
+
+    /// Views the same elements through a borrowed slice.
+    #[inline]
+    pub fn as_slice(&self) -> Slice<'_, K, I, T> {
+        Slice::new(&self.list, self.range())
+    }
+
+    pub fn iter(&self) -> Iter<'_, K, I, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns a reference to the whole underlying container.
+    pub fn get_ref(&self) -> &K {
+        &self.list
+    }
+
+    /// Gives back the underlying container, discarding the range.
+    pub fn into_inner(self) -> K {
+        self.list
+    }
+}
+
+impl<K, I, T> OwnedSlice<K, I, T>
+    where K: IndexMut<I, Output = T>,
+          I: Idx
+{
+    /// Returns a mutable reference to the element at `index`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: I) -> Option<&mut T> {
+        if index < self.len {
+            Some(&mut self.list[self.start + index])
+        } else {
+            None
+        }
+    }
+
+    /// Views the same elements through a borrowed mutable slice.
+    #[inline]
+    pub fn as_slice_mut(&mut self) -> SliceMut<'_, K, I, T> {
+        let range = self.range();
+        SliceMut::new(&mut self.list, range)
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, I, T>
+        where K: UniqueIndexMut<I>
+    {
+        self.as_slice_mut().iter_mut()
+    }
+}
+
+impl<K, I, T> Index<I> for OwnedSlice<K, I, T>
+    where K: Index<I, Output = T>,
+          I: Idx
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: I) -> &T {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, self.len),
+        }
+    }
+}
+
+impl<K, I, T> IndexMut<I> for OwnedSlice<K, I, T>
+    where K: IndexMut<I, Output = T>,
+          I: Idx
+{
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut T {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, len),
+        }
+    }
+}
+
+impl<K, I, T> TakeSlice<T, I> for OwnedSlice<K, I, T>
+    where K: Index<I, Output = T>,
+          I: Idx
+{
+    fn len(&self) -> I {
+        self.len
+    }
+}
+
+unsafe impl<K, I, T> UniqueIndexMut<I> for OwnedSlice<K, I, T>
+    where K: UniqueIndexMut<I, Output = T>,
+          I: Idx
+{
+}
+
+impl<'a, K, I, T> IntoIterator for &'a OwnedSlice<K, I, T>
+    where K: Index<I, Output = T>,
+          I: Idx
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, K, I, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, I, T> IntoIterator for &'a mut OwnedSlice<K, I, T>
+    where K: UniqueIndexMut<I, Output = T>,
+          I: Idx
+{
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, K, I, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K, I, T> Debug for OwnedSlice<K, I, T>
+    where K: Index<I, Output = T>,
+          I: Idx,
+          T: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use {OwnedSlice, TakeSlice};
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..5).collect()
+    }
+
+    fn window(n: usize) -> OwnedSlice<VecDeque<usize>, usize, usize> {
+        let mut v = test_vec();
+        v.push_front(n);
+        v.into_slice(1..5)
+    }
+
+    #[test]
+    fn owned_window() {
+        let mut w = window(9);
+        assert_eq!(w.len(), 4);
+        assert_eq!(w[0], 0);
+        assert_eq!(w.as_slice(), [0, 1, 2, 3]);
+        for x in &mut w {
+            *x *= 2;
+        }
+        w.index_range_mut(2..4).reverse();
+        let w = w.subslice(1..4);
+        assert_eq!(w.as_slice(), [2, 6, 4]);
+        assert_eq!(w.iter().sum::<usize>(), 12);
+        assert_eq!(format!("{:?}", w), "[2, 6, 4]");
+        assert_eq!(w.into_inner(), [9, 0, 2, 6, 4, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn owned_out_of_bounds() {
+        let w = window(0);
+        println!("{:?}", w[4]);
+    }
+}
@@ -0,0 +1,225 @@
+use core::cmp::Ordering;
+use core::ops::Index;
+use rayon;
+use rayon::iter::{ParallelIterator, IndexedParallelIterator, IntoParallelIterator, IntoParallelRefMutIterator};
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::slice::ParallelSliceMut;
+use super::{Idx, Slice, SliceMut, IndexedSlice, Iter, BoundsPolicy};
+use util::{to_usize, from_usize};
+
+impl<'a, K, I, T, P> Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T> + Sync,
+          I: Idx + Send + Sync,
+          T: Send + Sync,
+          P: BoundsPolicy
+{
+    /// Returns a parallel iterator over the elements, which is split
+    /// into sub-slices by index.
+    pub fn par_iter(self) -> ParIter<'a, K, I, T> {
+        ParIter { slice: self.with_policy() }
+    }
+}
+
+/// The parallel iterator for a mutable slice of an `IndexedSlice`.
+pub type ParIterMut<'a, T> = rayon::slice::IterMut<'a, T>;
+
+/// A parallel iterator over disjoint mutable chunks of a slice of an `IndexedSlice`.
+///
+/// Returned by `SliceMut::par_chunks_mut`.
+pub type ParChunksMut<'a, T> = rayon::slice::ChunksMut<'a, T>;
+
+// Views of other containers can't be processed in parallel: each of them
+// reaches its elements by borrowing the whole container, so two threads
+// would hold a `&mut K` at once. A view of an `IndexedSlice` is just a
+// `&mut [T]`, which rayon can already split between threads.
+impl<'a, 's, T, P> SliceMut<'a, IndexedSlice<'s, T>, usize, T, P>
+    where T: Send,
+          P: BoundsPolicy
+{
+    /// Returns a parallel iterator over mutable references to the elements.
+    pub fn par_iter_mut(self) -> ParIterMut<'a, T> {
+        self.into_mut_slice().par_iter_mut()
+    }
+
+    /// Returns a parallel iterator over `size` elements of the slice at a time,
+    /// like `chunks_mut`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn par_chunks_mut(self, size: usize) -> ParChunksMut<'a, T> {
+        assert!(size != 0, "chunk size must be non-zero");
+        self.into_mut_slice().par_chunks_mut(size)
+    }
+
+    /// Sorts the slice in parallel, but might not preserve the order of equal elements.
+    pub fn par_sort_unstable(&mut self)
+        where T: Ord
+    {
+        self.par_sort_unstable_by(|a, b| a.cmp(b))
+    }
+
+    /// Sorts the slice in parallel with a comparator function,
+    /// but might not preserve the order of equal elements.
+    pub fn par_sort_unstable_by<F>(&mut self, compare: F)
+        where F: Fn(&T, &T) -> Ordering + Sync
+    {
+        self.reborrow().into_mut_slice().par_sort_unstable_by(compare)
+    }
+
+    /// Sorts the slice in parallel with a key extraction function,
+    /// but might not preserve the order of equal elements.
+    pub fn par_sort_unstable_by_key<B, F>(&mut self, f: F)
+        where B: Ord,
+              F: Fn(&T) -> B + Sync
+    {
+        self.par_sort_unstable_by(|a, b| f(a).cmp(&f(b)))
+    }
+}
+
+impl<'a, K, I, T, P> IntoParallelIterator for Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T> + Sync,
+          I: Idx + Send + Sync,
+          T: Send + Sync,
+          P: BoundsPolicy
+{
+    type Item = &'a T;
+    type Iter = ParIter<'a, K, I, T>;
+    fn into_par_iter(self) -> Self::Iter {
+        self.par_iter()
+    }
+}
+
+impl<'a, 's, T, P> IntoParallelIterator for SliceMut<'a, IndexedSlice<'s, T>, usize, T, P>
+    where T: Send,
+          P: BoundsPolicy
+{
+    type Item = &'a mut T;
+    type Iter = ParIterMut<'a, T>;
+    fn into_par_iter(self) -> Self::Iter {
+        self.par_iter_mut()
+    }
+}
+
+/// The parallel iterator for an immutable slice.
+pub struct ParIter<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a> {
+    slice: Slice<'a, K, I, T>,
+}
+
+impl<'a, K, I, T> ParallelIterator for ParIter<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T> + Sync,
+          I: Idx + Send + Sync,
+          T: Send + Sync
+{
+    type Item = &'a T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<'a, K, I, T> IndexedParallelIterator for ParIter<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T> + Sync,
+          I: Idx + Send + Sync,
+          T: Send + Sync
+{
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn len(&self) -> usize {
+        to_usize(self.slice.len())
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(SliceProducer { slice: self.slice })
+    }
+}
+
+struct SliceProducer<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a> {
+    slice: Slice<'a, K, I, T>,
+}
+
+impl<'a, K, I, T> Producer for SliceProducer<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T> + Sync,
+          I: Idx + Send + Sync,
+          T: Send + Sync
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, K, I, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice.iter()
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.slice.split_at(from_usize(index));
+        (SliceProducer { slice: left }, SliceProducer { slice: right })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use rayon::prelude::*;
+    use {TakeSlice, IndexedSlice};
+
+    fn test_vec() -> VecDeque<u64> {
+        (0..10_000).collect()
+    }
+
+    #[test]
+    fn par_iter() {
+        let v = test_vec();
+        let sum: u64 = v.index_range(100..9_100).par_iter().sum();
+        assert_eq!(sum, (100..9_100).sum());
+        let found: Vec<_> = v.index_range(0..50).into_par_iter().filter(|x| *x % 7 == 0).collect();
+        assert_eq!(found, vec![&0, &7, &14, &21, &28, &35, &42, &49]);
+    }
+
+    #[test]
+    fn par_iter_mut() {
+        let mut v: Vec<u64> = (0..10_000).collect();
+        IndexedSlice::new(&mut v).index_range_mut(1..9_999).par_iter_mut().for_each(|x| *x *= 2);
+        assert_eq!(v[0], 0);
+        assert_eq!(v[1], 2);
+        assert_eq!(v[9_998], 19_996);
+        assert_eq!(v[9_999], 9_999);
+        let evens = IndexedSlice::new(&mut v).index_range_mut(0..10).into_par_iter().filter(|x| **x % 4 == 0).count();
+        assert_eq!(evens, 5);
+    }
+
+    #[test]
+    fn par_chunks_mut() {
+        let mut v: Vec<u64> = (0..10_000).collect();
+        {
+            let mut indexed = IndexedSlice::new(&mut v);
+            let chunks = indexed.index_range_mut(0..9_995).par_chunks_mut(10);
+            assert_eq!(chunks.len(), 1_000);
+            chunks.enumerate().for_each(|(i, chunk)| chunk.fill(i as u64));
+        }
+        assert_eq!(v[0], 0);
+        assert_eq!(v[19], 1);
+        assert_eq!(v[9_994], 999);
+        assert_eq!(v[9_995], 9_995);
+    }
+
+    #[test]
+    fn par_sort_unstable() {
+        // a scrambled permutation of 0..10_000
+        let mut v: Vec<u64> = (0..10_000).map(|i| i * 7_919 % 10_000).collect();
+        IndexedSlice::new(&mut v).index_range_mut(0..10_000).par_sort_unstable();
+        assert!(v.iter().cloned().eq(0..10_000));
+        IndexedSlice::new(&mut v).index_range_mut(10..9_990).par_sort_unstable_by(|a, b| b.cmp(a));
+        assert_eq!(v[9], 9);
+        assert_eq!(v[10], 9_989);
+        assert_eq!(v[9_989], 10);
+        assert_eq!(v[9_990], 9_990);
+    }
+}
@@ -0,0 +1,217 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use num_traits::One;
+use core::fmt::{self, Debug};
+use core::ops::Index;
+use num_traits::Zero;
+use util::{to_usize, from_usize};
+use {BoundsPolicy, Idx, Iter, Slice, TakeSlice};
+
+/// An immutable view which presents the elements of a slice in the order
+/// given by a list of indices, created with `Slice::permuted`.
+///
+/// No elements are moved. The order can be borrowed (`&[I]`) or owned
+/// (`Vec<I>`), and may skip or repeat indices.
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use owned_slice::TakeSlice;
+///
+/// let log: VecDeque<&str> = vec!["c", "a", "b"].into_iter().collect();
+/// let sorted = log.index_range_full(..).permuted([1, 2, 0]);
+/// assert_eq!(sorted[0], "a");
+/// assert_eq!(format!("{:?}", sorted), r#"["a", "b", "c"]"#);
+/// ```
+pub struct PermutedSlice<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a, O> {
+    slice: Slice<'a, K, I, T>,
+    order: O,
+}
+
+impl<'a, K, I, T, P> Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Returns a view whose element `i` is `self[order[i]]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index in `order` is out of bounds.
+    pub fn permuted<O>(self, order: O) -> PermutedSlice<'a, K, I, T, O>
+        where O: AsRef<[I]>
+    {
+        let len = self.len();
+        for &index in order.as_ref() {
+            if index >= len {
+                panic!("Index out of bounds: {:?} >= {:?}", index, len);
+            }
+        }
+        PermutedSlice { slice: self.with_policy(), order }
+    }
+
+    /// Returns a view of the slice in sorted order, leaving the elements in place.
+    ///
+    /// The sort is stable, so equal elements keep their original order.
+    #[cfg(feature = "alloc")]
+    pub fn sorted_view(self) -> PermutedSlice<'a, K, I, T, Vec<I>>
+        where T: Ord
+    {
+        self.sorted_view_by(Ord::cmp)
+    }
+
+    /// Returns a view of the slice sorted with a comparator function,
+    /// leaving the elements in place.
+    #[cfg(feature = "alloc")]
+    pub fn sorted_view_by<F>(self, mut compare: F) -> PermutedSlice<'a, K, I, T, Vec<I>>
+        where F: FnMut(&T, &T) -> core::cmp::Ordering
+    {
+        let mut order = Vec::with_capacity(to_usize(self.len()));
+        let mut i: I = Zero::zero();
+        while i < self.len() {
+            order.push(i);
+            i = i + One::one();
+        }
+        order.sort_by(|&a, &b| compare(&self[a], &self[b]));
+        PermutedSlice { slice: self.with_policy(), order }
+    }
+}
+
+impl<'a, K, I, T, O> PermutedSlice<'a, K, I, T, O>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          O: AsRef<[I]>
+{
+    /// Returns the number of elements in the view.
+    #[inline]
+    pub fn len(&self) -> I {
+        from_usize(self.order.as_ref().len())
+    }
+
+    /// Returns `true` if the view has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.order.as_ref().is_empty()
+    }
+
+    /// Returns a reference to the element at position `index` of the view,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: I) -> Option<&'a T> {
+        if index < Zero::zero() {
+            return None;
+        }
+        match self.order.as_ref().get(to_usize(index)) {
+            Some(&index) => self.slice.get(index),
+            None => None,
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, Self, I, T> {
+        self.index_range_full(..).iter()
+    }
+
+    /// Returns the indices into the original slice, in view order.
+    pub fn order(&self) -> &[I] {
+        self.order.as_ref()
+    }
+
+    /// Splits the view back into the original slice and the order.
+    pub fn into_parts(self) -> (Slice<'a, K, I, T>, O) {
+        (self.slice, self.order)
+    }
+}
+
+impl<'a, K, I, T, O> Clone for PermutedSlice<'a, K, I, T, O>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          O: Clone
+{
+    fn clone(&self) -> Self {
+        PermutedSlice {
+            slice: self.slice,
+            order: self.order.clone(),
+        }
+    }
+}
+
+impl<'a, K, I, T, O> Copy for PermutedSlice<'a, K, I, T, O>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          O: Copy
+{
+}
+
+impl<'a, K, I, T, O> Index<I> for PermutedSlice<'a, K, I, T, O>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          O: AsRef<[I]>
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: I) -> &T {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, self.len()),
+        }
+    }
+}
+
+impl<'a, K, I, T, O> TakeSlice<T, I> for PermutedSlice<'a, K, I, T, O>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          O: AsRef<[I]>
+{
+    fn len(&self) -> I {
+        from_usize(self.order.as_ref().len())
+    }
+}
+
+impl<'a, K, I, T, O> Debug for PermutedSlice<'a, K, I, T, O>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          T: Debug,
+          O: AsRef<[I]>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use TakeSlice;
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..10).collect()
+    }
+
+    #[test]
+    fn permuted() {
+        let v = test_vec();
+        let order = [4, 0, 0, 2];
+        let p = v.index_range(5..10).permuted(&order[..]);
+        assert_eq!(format!("{:?}", p), "[9, 5, 5, 7]");
+        assert_eq!(p.get(4), None);
+        assert_eq!(p.index_range(1..3), [5, 5]);
+        assert_eq!(p.order(), [4, 0, 0, 2]);
+    }
+
+    #[test]
+    fn sorted_view() {
+        let v: VecDeque<i32> = vec![3, -1, 2, -1, 0].into_iter().collect();
+        let s = v.index_range_full(..).sorted_view();
+        assert_eq!(format!("{:?}", s), "[-1, -1, 0, 2, 3]");
+        assert_eq!(s.order(), [1, 3, 4, 2, 0]);
+        let by_abs = v.index_range_full(..).sorted_view_by(|a, b| a.abs().cmp(&b.abs()));
+        assert_eq!(by_abs.iter().cloned().collect::<Vec<_>>(), [0, -1, -1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn permuted_out_of_bounds() {
+        test_vec().index_range_to(..3).permuted([3]);
+    }
+}
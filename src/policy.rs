@@ -0,0 +1,156 @@
+use util::{unlikely, predecessor};
+use Step;
+
+/// Decides how `Slice` and `SliceMut` check an index against their length
+/// when they are indexed with `[]`.
+///
+/// The policy is the last type parameter of the views, and defaults to
+/// `Checked`. It can be changed with `with_policy`. Methods like `get`
+/// always check, whatever the policy.
+///
+/// This trait is sealed: the views hand out elements based on the index
+/// it returns, so only the policies in this crate can implement it.
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use owned_slice::{Clamped, TakeSlice};
+///
+/// let deque: VecDeque<u32> = (0..5).collect();
+/// let slice = deque.index_range(1..3).with_policy::<Clamped>();
+/// assert_eq!(slice[7], 2);
+/// ```
+pub trait BoundsPolicy: sealed::Sealed {
+    /// Checks `index` against `len`, returning the index to use instead.
+    fn check_index<I: Step>(index: I, len: I) -> I;
+}
+
+/// A `BoundsPolicy` which never returns an index past the end of the view,
+/// so views can switch to it with the safe `with_policy`.
+///
+/// `DebugChecked` is the only policy which isn't one, and can only be
+/// chosen with the `unsafe` `with_policy_unchecked`:
+///
+/// ```compile_fail
+/// use std::collections::VecDeque;
+/// use owned_slice::{DebugChecked, TakeSlice};
+///
+/// let mut deque: VecDeque<u32> = (0..5).collect();
+/// let slice = deque.index_range_full_mut(..).with_policy::<DebugChecked>();
+/// ```
+pub trait SafePolicy: BoundsPolicy {}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::Checked {}
+    impl Sealed for super::DebugChecked {}
+    impl Sealed for super::Clamped {}
+}
+
+/// Panics if an index is out of bounds. This is the default.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Checked;
+
+/// Only checks indices when debug assertions are enabled.
+///
+/// Release builds leave the check to the container, so an index past
+/// the end of the view can silently reach elements after it. Those may
+/// be borrowed by another view, which is why switching to this policy
+/// needs `with_policy_unchecked`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DebugChecked;
+
+/// Clamps out of bounds indices to the last element.
+/// Indexing an empty slice still panics.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Clamped;
+
+impl BoundsPolicy for Checked {
+    #[inline]
+    fn check_index<I: Step>(index: I, len: I) -> I {
+        if unlikely(index >= len) {
+            panic!("Index out of bounds: {:?} >= {:?}", index, len);
+        }
+        index
+    }
+}
+
+impl BoundsPolicy for DebugChecked {
+    #[inline]
+    fn check_index<I: Step>(index: I, len: I) -> I {
+        debug_assert!(index < len, "Index out of bounds: {:?} >= {:?}", index, len);
+        index
+    }
+}
+
+impl BoundsPolicy for Clamped {
+    #[inline]
+    fn check_index<I: Step>(index: I, len: I) -> I {
+        if index < len {
+            index
+        } else if len == I::zero() {
+            Checked::check_index(index, len)
+        } else {
+            predecessor(len)
+        }
+    }
+}
+
+impl SafePolicy for Checked {}
+
+impl SafePolicy for Clamped {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use {Checked, Clamped, DebugChecked, TakeSlice};
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..10).collect()
+    }
+
+    #[test]
+    fn policies() {
+        let mut v = test_vec();
+        {
+            let mut s = v.index_range_mut(2..5).with_policy::<Clamped>();
+            s[9] = 0;
+            assert_eq!(s[1], 3);
+            let s = s.with_policy::<Checked>();
+            assert_eq!(s.as_slice(), [2, 3, 0]);
+        }
+
+        let s = unsafe { v.index_range(2..5).with_policy_unchecked::<DebugChecked>() };
+        assert_eq!(s[2], 0);
+        assert_eq!(s.iter().sum::<usize>(), 5);
+        assert_eq!(s.split_at(1).1.len(), 2);
+    }
+
+    #[test]
+    fn split_with_policy() {
+        let mut v = test_vec();
+        let mut s = v.index_range_mut(0..6).with_policy::<Clamped>();
+        s.sort_by(|a, b| b.cmp(a));
+        let (mut a, b) = s.split_at_mut(3);
+        a[5] = 9;
+        assert_eq!(format!("{:?} {:?}", a, b), "[5, 4, 9] [2, 1, 0]");
+        assert_eq!(b.chunks_mut(2).map(|c| c[7]).sum::<usize>(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamped_empty() {
+        let v = test_vec();
+        let s = v.index_range(3..3).with_policy::<Clamped>();
+        println!("{:?}", s[0]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn debug_checked() {
+        let v = test_vec();
+        let s = unsafe { v.index_range(3..5).with_policy_unchecked::<DebugChecked>() };
+        println!("{:?}", s[2]);
+    }
+}
@@ -0,0 +1,122 @@
+use core::marker;
+use core::ops::Index;
+use num_traits::{Zero, One};
+use rand::Rng;
+use super::{Idx, Slice, SliceMut, UniqueIndexMut, BoundsPolicy};
+use util::{to_usize, from_usize};
+
+impl<'a, K, I, T, P> Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Returns a uniformly random element of the slice,
+    /// or `None` if it is empty.
+    pub fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<&'a T> {
+        if self.is_empty() {
+            None
+        } else {
+            self.get(from_usize(rng.gen_range(0..to_usize(self.len))))
+        }
+    }
+}
+
+impl<'a, K, I, T, P> SliceMut<'a, K, I, T, P>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Shuffles the slice in place, using the Fisher-Yates algorithm.
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let mut i = self.len;
+        while i > One::one() {
+            let j = from_usize(rng.gen_range(0..to_usize(i)));
+            i = i - One::one();
+            self.swap(i, j);
+        }
+    }
+
+    /// Shuffles only `amount` elements of the slice, which are chosen
+    /// uniformly at random and moved to the end of the slice.
+    ///
+    /// Returns the shuffled elements, followed by the remaining elements
+    /// (in an unspecified order). If `amount` is greater than the length
+    /// of the slice, the whole slice is shuffled.
+    pub fn partial_shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R, amount: I)
+                                            -> (SliceMut<'_, K, I, T>, SliceMut<'_, K, I, T>) {
+        let m = if amount < self.len { self.len - amount } else { Zero::zero() };
+        let mut i = m;
+        while i < self.len {
+            let j = from_usize(rng.gen_range(0..to_usize(i) + 1));
+            self.swap(i, j);
+            i = i + One::one();
+        }
+        // `K: ?Sized + UniqueIndexMut` allows both halves to be used at once
+        (SliceMut {
+            list: self.list,
+            start: self.start + m,
+            len: self.len - m,
+            ty: marker::PhantomData,
+        },
+         SliceMut {
+            list: self.list,
+            start: self.start,
+            len: m,
+            ty: marker::PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use TakeSlice;
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..50).collect()
+    }
+
+    #[test]
+    fn shuffle() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut v = test_vec();
+        v.index_range_mut(10..40).shuffle(&mut rng);
+        assert_ne!(v, test_vec());
+        assert_eq!(v.index_range(0..10).iter().cloned().collect::<Vec<_>>(),
+                   (0..10).collect::<Vec<_>>());
+        let mut middle: Vec<_> = v.index_range(10..40).iter().cloned().collect();
+        middle.sort();
+        assert_eq!(middle, (10..40).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn partial_shuffle() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut v = test_vec();
+        {
+            let mut s = v.index_range_full_mut(..);
+            let (chosen, rest) = s.partial_shuffle(&mut rng, 5);
+            assert_eq!(chosen.len(), 5);
+            assert_eq!(rest.len(), 45);
+        }
+        let mut all: Vec<_> = v.iter().cloned().collect();
+        all.sort();
+        assert_eq!(all, (0..50).collect::<Vec<_>>());
+        let mut s = v.index_range_mut(0..3);
+        let (chosen, _) = s.partial_shuffle(&mut rng, 10);
+        assert_eq!(chosen.len(), 3);
+    }
+
+    #[test]
+    fn choose() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let v = test_vec();
+        for _ in 0..100 {
+            let x = *v.index_range(20..25).choose(&mut rng).unwrap();
+            assert!((20..25).contains(&x));
+        }
+        assert!(v.index_range(0..0).choose(&mut rng).is_none());
+    }
+}
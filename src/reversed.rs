@@ -0,0 +1,279 @@
+use core::fmt::{self, Debug};
+use core::ops::{Index, IndexMut};
+use num_traits::{Zero, One};
+use {BoundsPolicy, Idx, Iter, IterMut, Slice, SliceMut, TakeSlice, UniqueIndexMut};
+
+/// An immutable view of a slice in reverse order, created with `Slice::reversed`.
+///
+/// Index `0` is the last element of the original slice. Unlike
+/// `iter().rev()` this keeps random access, and it can be sliced again.
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use owned_slice::TakeSlice;
+///
+/// let deque: VecDeque<u32> = vec![1, 2, 3, 2, 1].into_iter().collect();
+/// let slice = deque.index_range_full(..);
+/// assert_eq!(slice, slice.reversed().index_range_full(..));
+/// assert_eq!(slice.reversed()[1], 2);
+/// ```
+pub struct Reversed<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a> {
+    slice: Slice<'a, K, I, T>,
+}
+
+/// A mutable view of a slice in reverse order, created with `SliceMut::reversed`.
+pub struct ReversedMut<'a, K: 'a + ?Sized + IndexMut<I, Output = T>, I: 'a + Idx, T: 'a> {
+    slice: SliceMut<'a, K, I, T>,
+}
+
+/// Maps an index from the end of a slice of length `len` to one from the start.
+#[inline]
+fn flip<I: Idx>(index: I, len: I) -> Option<I> {
+    if index < len {
+        Some(len - One::one() - index)
+    } else {
+        None
+    }
+}
+
+impl<'a, K, I, T, P> Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Returns a view of the slice in reverse order.
+    pub fn reversed(self) -> Reversed<'a, K, I, T> {
+        Reversed { slice: self.with_policy() }
+    }
+}
+
+impl<'a, K, I, T, P> SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Returns a mutable view of the slice in reverse order.
+    pub fn reversed(self) -> ReversedMut<'a, K, I, T> {
+        ReversedMut { slice: self.with_policy() }
+    }
+}
+
+impl<'a, K, I, T> Reversed<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    /// Returns the number of elements in the view.
+    #[inline]
+    pub fn len(&self) -> I {
+        self.slice.len()
+    }
+
+    /// Returns `true` if the view has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.slice.len() == Zero::zero()
+    }
+
+    /// Returns a reference to the element `index` places from the end
+    /// of the original slice, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: I) -> Option<&'a T> {
+        flip(index, self.slice.len()).and_then(|index| self.slice.get(index))
+    }
+
+    pub fn iter(&self) -> Iter<'_, Self, I, T> {
+        self.index_range_full(..).iter()
+    }
+
+    /// Gives back the slice in its original order.
+    pub fn into_inner(self) -> Slice<'a, K, I, T> {
+        self.slice
+    }
+}
+
+impl<'a, K, I, T> ReversedMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx
+{
+    /// Returns the number of elements in the view.
+    #[inline]
+    pub fn len(&self) -> I {
+        self.slice.len()
+    }
+
+    /// Returns `true` if the view has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.slice.len() == Zero::zero()
+    }
+
+    /// Returns a reference to the element `index` places from the end
+    /// of the original slice, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: I) -> Option<&T> {
+        flip(index, self.slice.len()).and_then(|index| self.slice.get(index))
+    }
+
+    /// Returns a mutable reference to the element `index` places from the end
+    /// of the original slice, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: I) -> Option<&mut T> {
+        match flip(index, self.slice.len()) {
+            Some(index) => self.slice.get_mut(index),
+            None => None,
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, Self, I, T> {
+        self.index_range_full(..).iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, Self, I, T>
+        where K: UniqueIndexMut<I>
+    {
+        self.index_range_full_mut(..).iter_mut()
+    }
+
+    /// Gives back the slice in its original order.
+    pub fn into_inner(self) -> SliceMut<'a, K, I, T> {
+        self.slice
+    }
+}
+
+impl<'a, K, I, T> Clone for Reversed<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, K, I, T> Copy for Reversed<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+}
+
+impl<'a, K, I, T> Index<I> for Reversed<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: I) -> &T {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, self.len()),
+        }
+    }
+}
+
+impl<'a, K, I, T> Index<I> for ReversedMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: I) -> &T {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, self.len()),
+        }
+    }
+}
+
+impl<'a, K, I, T> IndexMut<I> for ReversedMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx
+{
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut T {
+        let len = self.len();
+        match self.get_mut(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, len),
+        }
+    }
+}
+
+impl<'a, K, I, T> TakeSlice<T, I> for Reversed<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    fn len(&self) -> I {
+        self.slice.len()
+    }
+}
+
+impl<'a, K, I, T> TakeSlice<T, I> for ReversedMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx
+{
+    fn len(&self) -> I {
+        self.slice.len()
+    }
+}
+
+unsafe impl<'a, K, I, T> UniqueIndexMut<I> for ReversedMut<'a, K, I, T>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Idx
+{
+}
+
+impl<'a, K, I, T> Debug for Reversed<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          T: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K, I, T> Debug for ReversedMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          T: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use TakeSlice;
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..10).collect()
+    }
+
+    #[test]
+    fn reversed() {
+        let v = test_vec();
+        let r = v.index_range(2..6).reversed();
+        assert_eq!(format!("{:?}", r), "[5, 4, 3, 2]");
+        assert_eq!(r[0], 5);
+        assert_eq!(r.get(4), None);
+        assert_eq!(r.index_range(1..3), [4, 3]);
+        assert_eq!(r.index_range_full(..).binary_search_by(|x| 3.cmp(x)), Ok(2));
+        assert_eq!(r.into_inner(), [2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn reversed_mut() {
+        let mut v = test_vec();
+        {
+            let mut r = v.index_range_to_mut(..4).reversed();
+            r[0] = 9;
+            for (i, x) in r.index_range_from_mut(1..).iter_mut().enumerate() {
+                *x = i;
+            }
+        }
+        assert_eq!(v.index_range_to(..5), [2, 1, 0, 9, 4]);
+    }
+}
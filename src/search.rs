@@ -0,0 +1,336 @@
+use core::cmp::Ordering;
+use core::ops::{Index, IndexMut};
+use num_traits::{Zero, One};
+use super::{Idx, Slice, SliceMut, BoundsPolicy};
+use util::{to_usize, from_usize};
+
+impl<'a, K, I, T, P> Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Returns the index of the first element matching `pred`, if any.
+    pub fn position<F>(&self, mut pred: F) -> Option<I>
+        where F: FnMut(&T) -> bool
+    {
+        let end = self.start + self.len;
+        find_first(self.list, self.start, end, &mut pred).map(|i| i - self.start)
+    }
+
+    /// Returns the index of the last element matching `pred`, if any.
+    pub fn rposition<F>(&self, mut pred: F) -> Option<I>
+        where F: FnMut(&T) -> bool
+    {
+        let end = self.start + self.len;
+        find_last(self.list, self.start, end, &mut pred).map(|i| i - self.start)
+    }
+
+    /// Returns `true` if the slice contains an element equal to `x`.
+    pub fn contains(&self, x: &T) -> bool
+        where T: PartialEq
+    {
+        self.position(|y| y == x).is_some()
+    }
+
+    /// Returns `true` if `needle` is a prefix of the slice.
+    pub fn starts_with(&self, needle: &[T]) -> bool
+        where T: PartialEq
+    {
+        needle.len() <= to_usize(self.len) && self.iter().zip(needle).all(|(a, b)| a == b)
+    }
+
+    /// Returns `true` if `needle` is a suffix of the slice.
+    pub fn ends_with(&self, needle: &[T]) -> bool
+        where T: PartialEq
+    {
+        needle.len() <= to_usize(self.len) &&
+        self.iter().rev().zip(needle.iter().rev()).all(|(a, b)| a == b)
+    }
+
+    /// Returns `true` if `needle`, a slice over any container, is a prefix of the slice.
+    pub fn starts_with_slice<K2, I2>(&self, needle: &Slice<K2, I2, T>) -> bool
+        where K2: ?Sized + Index<I2, Output = T>,
+              I2: Idx,
+              T: PartialEq
+    {
+        to_usize(needle.len) <= to_usize(self.len) &&
+        self.iter().zip(needle.iter()).all(|(a, b)| a == b)
+    }
+
+    /// Returns `true` if `needle`, a slice over any container, is a suffix of the slice.
+    pub fn ends_with_slice<K2, I2>(&self, needle: &Slice<K2, I2, T>) -> bool
+        where K2: ?Sized + Index<I2, Output = T>,
+              I2: Idx,
+              T: PartialEq
+    {
+        to_usize(needle.len) <= to_usize(self.len) &&
+        self.iter().rev().zip(needle.iter().rev()).all(|(a, b)| a == b)
+    }
+
+    /// Binary searches this sorted slice for `x`.
+    ///
+    /// Returns `Ok` with the index of a matching element, or `Err` with
+    /// the index where `x` could be inserted while keeping the slice sorted.
+    /// If there are several matches, any one of them may be returned.
+    pub fn binary_search(&self, x: &T) -> Result<I, I>
+        where T: Ord
+    {
+        self.binary_search_by(|y| y.cmp(x))
+    }
+
+    /// Binary searches this slice with a comparator function, which should
+    /// return the ordering of each element relative to the target.
+    /// The slice must be sorted consistently with the comparator.
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<I, I>
+        where F: FnMut(&T) -> Ordering
+    {
+        let mut lo: I = Zero::zero();
+        let mut hi = self.len;
+        while lo < hi {
+            let mid = lo + from_usize(to_usize(hi - lo) / 2);
+            match f(&self.list[self.start + mid]) {
+                Ordering::Less => lo = mid + One::one(),
+                Ordering::Greater => hi = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(lo)
+    }
+
+    /// Binary searches this slice with a key extraction function.
+    /// The slice must be sorted by the key.
+    pub fn binary_search_by_key<B, F>(&self, b: &B, mut f: F) -> Result<I, I>
+        where B: Ord,
+              F: FnMut(&T) -> B
+    {
+        self.binary_search_by(|k| f(k).cmp(b))
+    }
+
+    /// Returns the index of the first element for which `pred` is false,
+    /// assuming the slice is partitioned so that all elements satisfying
+    /// `pred` come first.
+    pub fn partition_point<F>(&self, mut pred: F) -> I
+        where F: FnMut(&T) -> bool
+    {
+        self.binary_search_by(|x| if pred(x) { Ordering::Less } else { Ordering::Greater })
+            .unwrap_or_else(|i| i)
+    }
+
+    /// Returns the index of the first element not less than `x`
+    /// in this sorted slice.
+    pub fn lower_bound(&self, x: &T) -> I
+        where T: Ord
+    {
+        self.partition_point(|y| y < x)
+    }
+
+    /// Returns the index of the first element greater than `x`
+    /// in this sorted slice.
+    pub fn upper_bound(&self, x: &T) -> I
+        where T: Ord
+    {
+        self.partition_point(|y| y <= x)
+    }
+
+    /// Checks if the elements of this slice are sorted.
+    pub fn is_sorted(&self) -> bool
+        where T: PartialOrd
+    {
+        self.is_sorted_by(|a, b| a <= b)
+    }
+
+    /// Checks if the elements of this slice are sorted using the given
+    /// comparator, which should return `true` if its two arguments are in order.
+    pub fn is_sorted_by<F>(&self, mut compare: F) -> bool
+        where F: FnMut(&T, &T) -> bool
+    {
+        let mut iter = self.iter();
+        let mut prev = match iter.next() {
+            Some(first) => first,
+            None => return true,
+        };
+        for cur in iter {
+            if !compare(prev, cur) {
+                return false;
+            }
+            prev = cur;
+        }
+        true
+    }
+
+    /// Checks if the elements of this slice are sorted by the given key.
+    pub fn is_sorted_by_key<B, F>(&self, mut f: F) -> bool
+        where B: PartialOrd,
+              F: FnMut(&T) -> B
+    {
+        self.is_sorted_by(|a, b| f(a) <= f(b))
+    }
+}
+
+impl<'a, K, I, T, P> SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Binary searches this sorted slice for `x`.
+    /// See `Slice::binary_search`.
+    pub fn binary_search(&self, x: &T) -> Result<I, I>
+        where T: Ord
+    {
+        self.as_slice().binary_search(x)
+    }
+
+    /// Binary searches this slice with a comparator function.
+    /// See `Slice::binary_search_by`.
+    pub fn binary_search_by<F>(&self, f: F) -> Result<I, I>
+        where F: FnMut(&T) -> Ordering
+    {
+        self.as_slice().binary_search_by(f)
+    }
+
+    /// Binary searches this slice with a key extraction function.
+    /// See `Slice::binary_search_by_key`.
+    pub fn binary_search_by_key<B, F>(&self, b: &B, f: F) -> Result<I, I>
+        where B: Ord,
+              F: FnMut(&T) -> B
+    {
+        self.as_slice().binary_search_by_key(b, f)
+    }
+
+    /// Returns the index of the first element for which `pred` is false.
+    /// See `Slice::partition_point`.
+    pub fn partition_point<F>(&self, pred: F) -> I
+        where F: FnMut(&T) -> bool
+    {
+        self.as_slice().partition_point(pred)
+    }
+
+    /// Returns the index of the first element not less than `x`.
+    /// See `Slice::lower_bound`.
+    pub fn lower_bound(&self, x: &T) -> I
+        where T: Ord
+    {
+        self.as_slice().lower_bound(x)
+    }
+
+    /// Returns the index of the first element greater than `x`.
+    /// See `Slice::upper_bound`.
+    pub fn upper_bound(&self, x: &T) -> I
+        where T: Ord
+    {
+        self.as_slice().upper_bound(x)
+    }
+}
+
+/// Finds the first index in `start..end` whose element matches `pred`.
+pub fn find_first<K, I, T, P>(list: &K, start: I, end: I, pred: &mut P) -> Option<I>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: FnMut(&T) -> bool
+{
+    let mut i = start;
+    while i != end {
+        if pred(&list[i]) {
+            return Some(i);
+        }
+        i = i + One::one();
+    }
+    None
+}
+
+/// Finds the last index in `start..end` whose element matches `pred`.
+pub fn find_last<K, I, T, P>(list: &K, start: I, end: I, pred: &mut P) -> Option<I>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: FnMut(&T) -> bool
+{
+    let mut i = end;
+    while i != start {
+        i = i - One::one();
+        if pred(&list[i]) {
+            return Some(i);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use TakeSlice;
+
+    fn test_vec() -> VecDeque<u32> {
+        vec![5, 1, 4, 1, 5, 9, 2].into_iter().collect()
+    }
+
+    #[test]
+    fn position() {
+        let v = test_vec();
+        let s = v.index_range(1..6);
+        assert_eq!(s.position(|x| *x == 1), Some(0));
+        assert_eq!(s.rposition(|x| *x == 1), Some(2));
+        assert_eq!(s.position(|x| *x == 5), Some(3));
+        assert_eq!(s.position(|x| *x == 2), None);
+        assert_eq!(s.rposition(|x| *x == 2), None);
+        let i = s.position(|x| *x == 4).unwrap();
+        assert_eq!(s.split_at(i).1[0], 4);
+    }
+
+    #[test]
+    fn contains_and_affixes() {
+        let v = test_vec();
+        let s = v.index_range(1..6);
+        assert!(s.contains(&9));
+        assert!(!s.contains(&2));
+        assert!(s.starts_with(&[1, 4]));
+        assert!(s.starts_with(&[]));
+        assert!(!s.starts_with(&[4]));
+        assert!(s.ends_with(&[5, 9]));
+        assert!(!s.ends_with(&[1, 4, 1, 5, 9, 2]));
+        let other: Vec<u32> = vec![1, 5, 9];
+        let needle = ::Slice::new(&other, 1..3);
+        assert!(s.ends_with_slice(&needle));
+        assert!(!s.starts_with_slice(&needle));
+        assert!(v.index_range(3..6).starts_with_slice(&::Slice::new(&other, 0..3)));
+    }
+
+    #[test]
+    fn binary_search() {
+        let v: VecDeque<u32> = vec![0, 1, 1, 2, 3, 5, 8, 13].into_iter().collect();
+        let s = v.index_range(2..8);
+        assert_eq!(s.binary_search(&5), Ok(3));
+        assert_eq!(s.binary_search(&0), Err(0));
+        assert_eq!(s.binary_search(&4), Err(3));
+        assert_eq!(s.binary_search(&100), Err(6));
+        assert_eq!(s.binary_search_by(|x| x.cmp(&13)), Ok(5));
+        assert_eq!(s.binary_search_by_key(&16, |x| x * 2), Ok(4));
+        assert_eq!(v.index_range(0..0).binary_search(&1), Err(0));
+        let mut v = v;
+        assert_eq!(v.index_range_mut(1..8).binary_search(&2), Ok(2));
+    }
+
+    #[test]
+    fn partition_point_and_bounds() {
+        let mut v: VecDeque<u32> = vec![9, 1, 2, 2, 2, 3, 5].into_iter().collect();
+        let s = v.index_range(1..7);
+        assert_eq!(s.partition_point(|x| *x < 3), 4);
+        assert_eq!(s.lower_bound(&2), 1);
+        assert_eq!(s.upper_bound(&2), 4);
+        assert_eq!(s.lower_bound(&0), 0);
+        assert_eq!(s.upper_bound(&9), 6);
+        let range = s.lower_bound(&2)..s.upper_bound(&2);
+        assert_eq!(s.split_at(range.end).0.split_at(range.start).1.len(), 3);
+        assert_eq!(v.index_range_mut(1..7).upper_bound(&3), 5);
+    }
+
+    #[test]
+    fn is_sorted() {
+        let v = test_vec();
+        assert!(!v.index_range(2..6).is_sorted());
+        assert!(v.index_range(3..6).is_sorted());
+        assert!(v.index_range(0..0).is_sorted());
+        assert!(v.index_range(5..7).is_sorted_by(|a, b| a >= b));
+        assert!(v.index_range(5..7).is_sorted_by_key(|x| -(*x as i32)));
+        assert!(!v.index_range(3..6).is_sorted_by_key(|x| -(*x as i32)));
+    }
+}
@@ -0,0 +1,144 @@
+use core::fmt;
+use core::ops::{Index, IndexMut};
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::{DeserializeSeed, Visitor, SeqAccess, IgnoredAny, Error};
+use num_traits::{Zero, One};
+use super::{Idx, Slice, SliceMut, BoundsPolicy, Checked};
+use util::to_usize;
+
+impl<'a, K, I, T, P> Serialize for Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy,
+          T: Serialize
+{
+    /// Serializes the slice as a sequence of its elements, like `[T]`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'a, K, I, T, P> Serialize for SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy,
+          T: Serialize
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+impl<'a, K, I, T, P> SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Deserializes a sequence directly into the slice, overwriting its elements
+    /// in place instead of allocating a new collection.
+    ///
+    /// Fails with an `invalid_length` error if the sequence does not have
+    /// exactly `self.len()` elements, in which case the slice may have been
+    /// partially overwritten.
+    pub fn deserialize_in_place<'de, D>(&mut self, deserializer: D) -> Result<(), D::Error>
+        where D: Deserializer<'de>,
+              T: Deserialize<'de>
+    {
+        DeserializeInto(self).deserialize(deserializer)
+    }
+}
+
+/// A `DeserializeSeed` which deserializes a sequence into an existing `SliceMut`.
+///
+/// See `SliceMut::deserialize_in_place`.
+pub struct DeserializeInto<'s, 'a: 's, K: 'a + ?Sized + IndexMut<I, Output = T>, I: 'a + Idx, T: 'a, P: BoundsPolicy = Checked>(
+    pub &'s mut SliceMut<'a, K, I, T, P>
+);
+
+impl<'de, 's, 'a, K, I, T, P> DeserializeSeed<'de> for DeserializeInto<'s, 'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy,
+          T: Deserialize<'de>
+{
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, 's, 'a, K, I, T, P> Visitor<'de> for DeserializeInto<'s, 'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy,
+          T: Deserialize<'de>
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a sequence of {} elements", to_usize(self.0.len()))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let len = self.0.len();
+        let mut i = Zero::zero();
+        while i < len {
+            match seq.next_element()? {
+                Some(value) => self.0[i] = value,
+                None => return Err(A::Error::invalid_length(to_usize(i), &self)),
+            }
+            i = i + One::one();
+        }
+        if seq.next_element::<IgnoredAny>()?.is_some() {
+            return Err(A::Error::invalid_length(to_usize(len) + 1, &self));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use serde::de::value::{SeqDeserializer, Error};
+    use serde_test::{assert_ser_tokens, Token};
+    use TakeSlice;
+
+    fn test_vec() -> VecDeque<u32> {
+        vec![1, 2, 3, 4, 5].into_iter().collect()
+    }
+
+    #[test]
+    fn serialize_as_sequence() {
+        let mut v = test_vec();
+        assert_ser_tokens(&v.index_range(1..4), &[
+            Token::Seq { len: Some(3) },
+            Token::U32(2),
+            Token::U32(3),
+            Token::U32(4),
+            Token::SeqEnd,
+        ]);
+        assert_ser_tokens(&v.index_range(0..0), &[Token::Seq { len: Some(0) }, Token::SeqEnd]);
+        assert_ser_tokens(&v.index_range_mut(3..5), &[
+            Token::Seq { len: Some(2) },
+            Token::U32(4),
+            Token::U32(5),
+            Token::SeqEnd,
+        ]);
+    }
+
+    #[test]
+    fn deserialize_in_place() {
+        let mut v = test_vec();
+        let de = SeqDeserializer::<_, Error>::new(vec![7u32, 8, 9].into_iter());
+        v.index_range_mut(1..4).deserialize_in_place(de).unwrap();
+        assert_eq!(v, vec![1, 7, 8, 9, 5]);
+
+        let de = SeqDeserializer::<_, Error>::new(vec![0u32].into_iter());
+        let err = v.index_range_mut(0..2).deserialize_in_place(de).unwrap_err();
+        assert_eq!(err.to_string(), "invalid length 1, expected a sequence of 2 elements");
+
+        let de = SeqDeserializer::<_, Error>::new(vec![0u32, 0, 0].into_iter());
+        assert!(v.index_range_mut(0..2).deserialize_in_place(de).is_err());
+    }
+}
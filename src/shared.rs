@@ -0,0 +1,228 @@
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use core::fmt::{self, Debug};
+use core::marker;
+use core::ops::{Deref, Index, Range};
+use num_traits::Zero;
+use util::assert_in_bounds;
+use {Idx, Iter, Slice, TakeSlice};
+
+/// A read-only slice which shares ownership of its container through
+/// a reference-counted pointer such as `Rc` or `Arc`.
+///
+/// Cloning a `SharedSlice` only clones the pointer, so many overlapping
+/// windows of one large container can be handed out without copying it
+/// or tying them to a borrow. `ArcSlice` is `Send` and `Sync` whenever
+/// the container is.
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use std::sync::Arc;
+/// use owned_slice::ArcSlice;
+///
+/// let deque: VecDeque<u32> = (0..8).collect();
+/// let whole: ArcSlice<_, _, _> = ArcSlice::new(Arc::new(deque), 0..8);
+/// let (front, back) = whole.split_at(4);
+/// let handle = std::thread::spawn(move || back.iter().sum::<u32>());
+/// assert_eq!(front.iter().sum::<u32>(), 6);
+/// assert_eq!(handle.join().unwrap(), 22);
+/// ```
+pub struct SharedSlice<P, I: Idx, T> {
+    list: P,
+    start: I,
+    len: I,
+    ty: marker::PhantomData<T>,
+}
+
+/// A `SharedSlice` backed by an `Rc`.
+pub type RcSlice<K, I, T> = SharedSlice<Rc<K>, I, T>;
+
+/// A `SharedSlice` backed by an `Arc`, which can be sent between threads.
+pub type ArcSlice<K, I, T> = SharedSlice<Arc<K>, I, T>;
+
+impl<P, I, T> SharedSlice<P, I, T>
+    where P: Deref + Clone,
+          P::Target: TakeSlice<T, I>,
+          I: Idx
+{
+    /// Creates a shared slice of `list` covering `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of the bounds of the container.
+    pub fn new(list: P, index: Range<I>) -> SharedSlice<P, I, T> {
+        assert_in_bounds(&index, list.len());
+        SharedSlice {
+            list,
+            start: index.start,
+            len: index.end - index.start,
+            ty: marker::PhantomData,
+        }
+    }
+}
+
+impl<P, I, T> SharedSlice<P, I, T>
+    where P: Deref + Clone,
+          P::Target: Index<I, Output = T>,
+          I: Idx
+{
+    /// Returns the number of elements in the slice.
+    #[inline]
+    pub fn len(&self) -> I {
+        self.len
+    }
+
+    /// Returns `true` if the slice has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == Zero::zero()
+    }
+
+    /// Returns a reference to the element at `index`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: I) -> Option<&T> {
+        if index < self.len {
+            Some(&self.list[self.start + index])
+        } else {
+            None
+        }
+    }
+
+    /// Returns another handle to the elements in `range`, which is relative
+    /// to this slice. Only the pointer is cloned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of the bounds of this slice.
+    pub fn subslice(&self, range: Range<I>) -> SharedSlice<P, I, T> {
+        assert_in_bounds(&range, self.len);
+        SharedSlice {
+            list: self.list.clone(),
+            start: self.start + range.start,
+            len: range.end - range.start,
+            ty: marker::PhantomData,
+        }
+    }
+
+    /// Divides the slice into two handles at an index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn split_at(&self, mid: I) -> (SharedSlice<P, I, T>, SharedSlice<P, I, T>) {
+        assert_in_bounds(&(Zero::zero()..mid), self.len);
+        (self.subslice(Zero::zero()..mid), self.subslice(mid..self.len))
+    }
+
+    /// Views the same elements through a borrowed slice.
+    #[inline]
+    pub fn as_slice(&self) -> Slice<'_, P::Target, I, T> {
+        Slice::new(&*self.list, self.start..self.start + self.len)
+    }
+
+    pub fn iter(&self) -> Iter<'_, P::Target, I, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns the pointer to the whole underlying container.
+    pub fn container(&self) -> &P {
+        &self.list
+    }
+}
+
+impl<P, I, T> Clone for SharedSlice<P, I, T>
+    where P: Clone,
+          I: Idx
+{
+    fn clone(&self) -> Self {
+        SharedSlice {
+            list: self.list.clone(),
+            start: self.start,
+            len: self.len,
+            ty: marker::PhantomData,
+        }
+    }
+}
+
+impl<P, I, T> Index<I> for SharedSlice<P, I, T>
+    where P: Deref + Clone,
+          P::Target: Index<I, Output = T>,
+          I: Idx
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: I) -> &T {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, self.len),
+        }
+    }
+}
+
+impl<P, I, T> TakeSlice<T, I> for SharedSlice<P, I, T>
+    where P: Deref + Clone,
+          P::Target: Index<I, Output = T>,
+          I: Idx
+{
+    fn len(&self) -> I {
+        self.len
+    }
+}
+
+impl<'a, P, I, T> IntoIterator for &'a SharedSlice<P, I, T>
+    where P: Deref + Clone,
+          P::Target: 'a + Index<I, Output = T>,
+          I: Idx
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, P::Target, I, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<P, I, T> Debug for SharedSlice<P, I, T>
+    where P: Deref + Clone,
+          P::Target: Index<I, Output = T>,
+          I: Idx,
+          T: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+    use {RcSlice, TakeSlice};
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..10).collect()
+    }
+
+    #[test]
+    fn shared_windows() {
+        let list = Rc::new(test_vec());
+        let a: RcSlice<_, _, _> = RcSlice::new(list.clone(), 2..8);
+        let b = a.subslice(3..6);
+        assert_eq!(Rc::strong_count(&list), 3);
+        assert_eq!(a.as_slice(), [2, 3, 4, 5, 6, 7]);
+        assert_eq!(b.as_slice(), [5, 6, 7]);
+        assert_eq!(b[1], 6);
+        assert_eq!(a.index_range(1..3), [3, 4]);
+        let (x, y) = b.split_at(1);
+        assert_eq!(format!("{:?} {:?}", x, y), "[5] [6, 7]");
+        drop((a, b, x, y));
+        assert_eq!(Rc::strong_count(&list), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn shared_out_of_bounds() {
+        RcSlice::new(Rc::new(test_vec()), 5..11);
+    }
+}
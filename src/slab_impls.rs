@@ -0,0 +1,97 @@
+use slab::Slab;
+use super::{Slice, TakeSlice};
+
+/// Slices of a `Slab` cover a range of keys, up to one past the highest
+/// occupied key. Indexing a vacant key panics, like indexing the `Slab`
+/// itself does, so use `Slice::iter_occupied` to walk a range of keys
+/// which might contain vacancies.
+///
+/// `Slab` is not `UniqueIndexMut`, as `index_mut` goes through a slice of
+/// all the entries, so slices of it can't be split or iterated mutably.
+impl<T> TakeSlice<T, usize> for Slab<T> {
+    fn len(&self) -> usize {
+        self.iter().next_back().map_or(0, |(key, _)| key + 1)
+    }
+}
+
+impl<'a, T> Slice<'a, Slab<T>, usize, T> {
+    /// Returns an iterator over the occupied entries in the slice,
+    /// yielding each one's key in the `Slab` along with its value.
+    pub fn iter_occupied(self) -> IterOccupied<'a, T> {
+        IterOccupied {
+            slab: self.list,
+            cur: self.start,
+            end: self.start + self.len,
+        }
+    }
+}
+
+/// An iterator over the occupied entries of a slice of a `Slab`.
+///
+/// Returned by `Slice::iter_occupied`.
+pub struct IterOccupied<'a, T: 'a> {
+    slab: &'a Slab<T>,
+    cur: usize,
+    end: usize,
+}
+
+impl<'a, T> Iterator for IterOccupied<'a, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.cur < self.end {
+            let key = self.cur;
+            self.cur += 1;
+            if let Some(value) = self.slab.get(key) {
+                return Some((key, value));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.end - self.cur))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use slab::Slab;
+    use TakeSlice;
+
+    fn test_slab() -> Slab<&'static str> {
+        let mut slab = Slab::new();
+        for name in &["a", "b", "c", "d", "e"] {
+            slab.insert(*name);
+        }
+        slab.remove(1);
+        slab.remove(4);
+        slab
+    }
+
+    #[test]
+    fn take_slice() {
+        let mut slab = test_slab();
+        assert_eq!(TakeSlice::len(&slab), 4);
+        assert_eq!(slab.index_range(2..4), ["c", "d"]);
+        slab.index_range_mut(2..4)[1] = "e";
+        assert_eq!(slab[3], "e");
+        assert!(slab.try_index_range(0..5).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn vacant_key_panics() {
+        let slab = test_slab();
+        let _ = slab.index_range(0..3)[1];
+    }
+
+    #[test]
+    fn iter_occupied() {
+        let slab = test_slab();
+        let occupied: Vec<_> = slab.index_range(0..4).iter_occupied().collect();
+        assert_eq!(occupied, vec![(0, &"a"), (2, &"c"), (3, &"d")]);
+        assert_eq!(slab.index_range(1..2).iter_occupied().next(), None);
+    }
+}
@@ -0,0 +1,30 @@
+use smallvec::{Array, SmallVec};
+use super::TakeSlice;
+
+// `SmallVec` is deliberately not `UniqueIndexMut`: `index_mut` goes through
+// a slice of all the elements, which may even be stored inline. Wrap it in
+// an `IndexedSlice` to split or iterate it mutably.
+impl<A: Array> TakeSlice<A::Item, usize> for SmallVec<A> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use smallvec::SmallVec;
+    use {TakeSlice, IndexedSlice};
+
+    #[test]
+    fn take_slice() {
+        let mut inline: SmallVec<[u32; 8]> = (0..5).collect();
+        let mut spilled: SmallVec<[u32; 2]> = (0..5).collect();
+        assert!(spilled.spilled());
+        assert_eq!(inline.index_range(1..4), [1, 2, 3]);
+        inline.index_range_mut(0..5).map_in_place(|x| 4 - x);
+        IndexedSlice::new(&mut spilled).index_range_mut(0..5).reverse();
+        assert_eq!(&inline[..], &[4, 3, 2, 1, 0]);
+        assert_eq!(inline, spilled);
+        assert!(spilled.try_index_range(3..6).is_err());
+    }
+}
@@ -0,0 +1,483 @@
+use core::cmp::Ordering;
+use core::marker;
+use core::ops::{IndexMut, Range};
+use num_traits::{Zero, One};
+use super::{Idx, SliceMut, UniqueIndexMut, BoundsPolicy};
+use util::{to_usize, from_usize, index_unique};
+
+/// The elements before, at and after the selected index.
+type SelectNth<'b, K, I, T, P> = (SliceMut<'b, K, I, T, P>, &'b mut T, SliceMut<'b, K, I, T, P>);
+
+/// Slices at most this long are sorted with insertion sort.
+const INSERTION_SORT_THRESHOLD: usize = 20;
+
+impl<'a, K, I, T, P> SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Sorts the slice, preserving the order of equal elements.
+    ///
+    /// This is an in-place merge sort which never allocates,
+    /// running in `O(n log² n)` time.
+    pub fn sort(&mut self)
+        where T: Ord
+    {
+        self.sort_by(|a, b| a.cmp(b))
+    }
+
+    /// Sorts the slice with a comparator function,
+    /// preserving the order of equal elements.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        let len = self.len;
+        self.merge_sort(Zero::zero(), len, &mut |a, b| compare(a, b) == Ordering::Less);
+    }
+
+    /// Sorts the slice with a key extraction function,
+    /// preserving the order of equal elements.
+    pub fn sort_by_key<B, F>(&mut self, mut f: F)
+        where B: Ord,
+              F: FnMut(&T) -> B
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Sorts the slice, but might not preserve the order of equal elements.
+    ///
+    /// This is an in-place heapsort, running in `O(n log n)` time.
+    pub fn sort_unstable(&mut self)
+        where T: Ord
+    {
+        self.sort_unstable_by(|a, b| a.cmp(b))
+    }
+
+    /// Sorts the slice with a comparator function,
+    /// but might not preserve the order of equal elements.
+    pub fn sort_unstable_by<F>(&mut self, mut compare: F)
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        self.heap_sort(&mut |a, b| compare(a, b) == Ordering::Less);
+    }
+
+    /// Sorts the slice with a key extraction function,
+    /// but might not preserve the order of equal elements.
+    pub fn sort_unstable_by_key<B, F>(&mut self, mut f: F)
+        where B: Ord,
+              F: FnMut(&T) -> B
+    {
+        self.sort_unstable_by(|a, b| f(a).cmp(&f(b)))
+    }
+
+    #[inline]
+    fn is_less<F>(&self, is_less: &mut F, a: I, b: I) -> bool
+        where F: FnMut(&T, &T) -> bool
+    {
+        let list = self.list();
+        is_less(&list[self.start + a], &list[self.start + b])
+    }
+
+    fn insertion_sort<F>(&mut self, lo: I, hi: I, is_less: &mut F)
+        where F: FnMut(&T, &T) -> bool
+    {
+        if lo >= hi {
+            return;
+        }
+        let mut i = lo + One::one();
+        while i < hi {
+            let mut j = i;
+            while j > lo && self.is_less(is_less, j, j - One::one()) {
+                self.swap(j, j - One::one());
+                j = j - One::one();
+            }
+            i = i + One::one();
+        }
+    }
+
+    fn merge_sort<F>(&mut self, lo: I, hi: I, is_less: &mut F)
+        where F: FnMut(&T, &T) -> bool
+    {
+        let len = to_usize(hi - lo);
+        if len <= INSERTION_SORT_THRESHOLD {
+            self.insertion_sort(lo, hi, is_less);
+            return;
+        }
+        let mid = lo + from_usize(len / 2);
+        self.merge_sort(lo, mid, is_less);
+        self.merge_sort(mid, hi, is_less);
+        if self.is_less(is_less, mid, mid - One::one()) {
+            self.merge(lo, mid, hi, is_less);
+        }
+    }
+
+    /// Merges the sorted runs `lo..mid` and `mid..hi` without a buffer,
+    /// by rotating elements into place.
+    fn merge<F>(&mut self, lo: I, mid: I, hi: I, is_less: &mut F)
+        where F: FnMut(&T, &T) -> bool
+    {
+        if lo == mid || mid == hi {
+            return;
+        }
+        let (len1, len2) = (to_usize(mid - lo), to_usize(hi - mid));
+        if len1 + len2 == 2 {
+            if self.is_less(is_less, mid, lo) {
+                self.swap(mid, lo);
+            }
+            return;
+        }
+        let (cut1, cut2);
+        if len1 > len2 {
+            cut1 = lo + from_usize(len1 / 2);
+            // the first element of the right run not less than `cut1`
+            let (mut l, mut h) = (mid, hi);
+            while l < h {
+                let m = l + from_usize(to_usize(h - l) / 2);
+                if self.is_less(is_less, m, cut1) { l = m + One::one() } else { h = m }
+            }
+            cut2 = l;
+        } else {
+            cut2 = mid + from_usize(len2 / 2);
+            // the first element of the left run greater than `cut2`
+            let (mut l, mut h) = (lo, mid);
+            while l < h {
+                let m = l + from_usize(to_usize(h - l) / 2);
+                if self.is_less(is_less, cut2, m) { h = m } else { l = m + One::one() }
+            }
+            cut1 = l;
+        }
+        // rotate `cut1..mid` past `mid..cut2`
+        self.reverse_range(cut1, mid);
+        self.reverse_range(mid, cut2);
+        self.reverse_range(cut1, cut2);
+        let new_mid = cut1 + (cut2 - mid);
+        self.merge(lo, cut1, new_mid, is_less);
+        self.merge(new_mid, cut2, hi, is_less);
+    }
+
+    fn heap_sort<F>(&mut self, is_less: &mut F)
+        where F: FnMut(&T, &T) -> bool
+    {
+        let len = self.len;
+        let mut i = from_usize(to_usize(len) / 2);
+        while i != Zero::zero() {
+            i = i - One::one();
+            self.sift_down(i, len, is_less);
+        }
+        let mut end = len;
+        while end > One::one() {
+            end = end - One::one();
+            self.swap(Zero::zero(), end);
+            self.sift_down(Zero::zero(), end, is_less);
+        }
+    }
+
+    /// Restores the max-heap property below `node`, within `0..end`.
+    fn sift_down<F>(&mut self, mut node: I, end: I, is_less: &mut F)
+        where F: FnMut(&T, &T) -> bool
+    {
+        loop {
+            // `node` has a child if `2 * node + 1 < end`, checked
+            // as `node < end / 2` so the index type can't overflow
+            if node >= from_usize(to_usize(end) / 2) {
+                break;
+            }
+            let mut child = node + node + One::one();
+            if child + One::one() < end && self.is_less(is_less, child, child + One::one()) {
+                child = child + One::one();
+            }
+            if !self.is_less(is_less, node, child) {
+                break;
+            }
+            self.swap(node, child);
+            node = child;
+        }
+    }
+}
+
+impl<'a, K, I, T, P> SliceMut<'a, K, I, T, P>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Reorders the slice so that the element at `index` is in its final
+    /// sorted position, every element before it is less than or equal to it,
+    /// and every element after it is greater than or equal to it.
+    ///
+    /// Returns the elements before `index`, the element at `index`,
+    /// and the elements after it. This is a quickselect, running in
+    /// `O(n)` time on average.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn select_nth_unstable(&mut self, index: I) -> SelectNth<'_, K, I, T, P>
+        where T: Ord
+    {
+        self.select_nth_unstable_by(index, |a, b| a.cmp(b))
+    }
+
+    /// Like `select_nth_unstable`, but with a comparator function.
+    pub fn select_nth_unstable_by<F>(&mut self, index: I, mut compare: F) -> SelectNth<'_, K, I, T, P>
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        if index >= self.len {
+            panic!("Index out of bounds: {:?} >= {:?}", index, self.len);
+        }
+        self.quickselect(index, &mut |a, b| compare(a, b) == Ordering::Less);
+        let after = index + One::one();
+        (SliceMut {
+            list: self.list,
+            start: self.start,
+            len: index,
+            ty: marker::PhantomData,
+        },
+         // `K: ?Sized + UniqueIndexMut` ensures the three parts don't alias
+         unsafe { index_unique(self.list, self.start + index) },
+         SliceMut {
+            list: self.list,
+            start: self.start + after,
+            len: self.len - after,
+            ty: marker::PhantomData,
+        })
+    }
+
+    /// Like `select_nth_unstable`, but with a key extraction function.
+    pub fn select_nth_unstable_by_key<B, F>(&mut self, index: I, mut f: F) -> SelectNth<'_, K, I, T, P>
+        where B: Ord,
+              F: FnMut(&T) -> B
+    {
+        self.select_nth_unstable_by(index, |a, b| f(a).cmp(&f(b)))
+    }
+
+    fn quickselect<F>(&mut self, target: I, is_less: &mut F)
+        where F: FnMut(&T, &T) -> bool
+    {
+        let (mut lo, mut hi) = (Zero::zero(), self.len);
+        // after this many bad partitions, give up and heapsort what's left
+        let mut limit = 2 * (usize::BITS - to_usize(self.len).leading_zeros());
+        loop {
+            let len = to_usize(hi - lo);
+            if len <= INSERTION_SORT_THRESHOLD {
+                self.insertion_sort(lo, hi, is_less);
+                return;
+            }
+            if limit == 0 {
+                SliceMut::<K, I, T, P> {
+                    list: self.list,
+                    start: self.start + lo,
+                    len: hi - lo,
+                    ty: marker::PhantomData,
+                }.heap_sort(is_less);
+                return;
+            }
+            limit -= 1;
+            let equal = self.partition(lo, hi, is_less);
+            if target < equal.start {
+                hi = equal.start;
+            } else if target >= equal.end {
+                lo = equal.end;
+            } else {
+                return;
+            }
+        }
+    }
+
+    /// Partitions `lo..hi` around the median of its first, middle and last
+    /// elements, returning the range of elements equal to the pivot.
+    /// Everything before it is less than the pivot, and everything after it
+    /// is greater. Requires `hi - lo >= 3`.
+    fn partition<F>(&mut self, lo: I, hi: I, is_less: &mut F) -> Range<I>
+        where F: FnMut(&T, &T) -> bool
+    {
+        // move the median of the first, middle and last elements to the front
+        let len = to_usize(hi - lo);
+        let (a, b, c) = (lo, lo + from_usize(len / 2), hi - One::one());
+        if self.is_less(is_less, b, a) {
+            self.swap(a, b);
+        }
+        if self.is_less(is_less, c, b) {
+            self.swap(b, c);
+            if self.is_less(is_less, b, a) {
+                self.swap(a, b);
+            }
+        }
+        self.swap(a, b);
+        // a three-way partition, so runs of equal elements don't degrade it:
+        // `lo..lt` is less than the pivot, `lt..i` equal to it
+        // (so `lt` always holds a copy of it), and `gt..hi` greater
+        let (mut lt, mut i, mut gt) = (lo, lo + One::one(), hi);
+        while i < gt {
+            if self.is_less(is_less, i, lt) {
+                self.swap(i, lt);
+                lt = lt + One::one();
+                i = i + One::one();
+            } else if self.is_less(is_less, lt, i) {
+                gt = gt - One::one();
+                self.swap(i, gt);
+            } else {
+                i = i + One::one();
+            }
+        }
+        lt..gt
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use TakeSlice;
+
+    // a small deterministic pseudo-random sequence
+    fn scrambled(n: usize) -> VecDeque<u32> {
+        let mut x = 12345u32;
+        (0..n)
+            .map(|_| {
+                x = x.wrapping_mul(1103515245).wrapping_add(12345);
+                (x >> 16) % 100
+            })
+            .collect()
+    }
+
+    fn sorted(v: &VecDeque<u32>) -> Vec<u32> {
+        let mut v: Vec<_> = v.iter().cloned().collect();
+        v.sort();
+        v
+    }
+
+    #[test]
+    fn sort() {
+        for &n in &[0, 1, 2, 5, 20, 21, 100, 257] {
+            let mut v = scrambled(n);
+            let expected = sorted(&v);
+            v.index_range_full_mut(..).sort();
+            assert_eq!(v, expected);
+        }
+    }
+
+    #[test]
+    fn sort_is_stable() {
+        let mut v: VecDeque<(u32, usize)> = scrambled(200)
+            .into_iter()
+            .enumerate()
+            .map(|(i, x)| (x % 10, i))
+            .collect();
+        let mut expected: Vec<_> = v.iter().cloned().collect();
+        expected.sort_by_key(|p| p.0);
+        v.index_range_full_mut(..).sort_by_key(|p| p.0);
+        assert_eq!(v, expected);
+    }
+
+    #[test]
+    fn sort_unstable() {
+        for &n in &[0, 1, 2, 5, 20, 21, 100, 257] {
+            let mut v = scrambled(n);
+            let expected = sorted(&v);
+            v.index_range_full_mut(..).sort_unstable();
+            assert_eq!(v, expected);
+        }
+        let mut v = scrambled(50);
+        v.index_range_full_mut(..).sort_unstable_by(|a, b| b.cmp(a));
+        assert!(v.index_range_full(..).is_sorted_by(|a, b| a >= b));
+    }
+
+    #[test]
+    fn sort_vec() {
+        let mut v: Vec<_> = scrambled(100).into_iter().collect();
+        let mut expected = v.clone();
+        expected.sort();
+        v.index_range_full_mut(..).sort();
+        assert_eq!(v, expected);
+        v.reverse();
+        v.index_range_full_mut(..).sort_unstable();
+        assert_eq!(v, expected);
+    }
+
+    #[test]
+    fn sort_sub_slice() {
+        let mut v = scrambled(60);
+        let before: Vec<_> = v.iter().cloned().collect();
+        v.index_range_mut(10..50).sort_by(|a, b| b.cmp(a));
+        assert_eq!(v.index_range(0..10).iter().cloned().collect::<Vec<_>>(), &before[..10]);
+        assert_eq!(v.index_range(50..60).iter().cloned().collect::<Vec<_>>(), &before[50..]);
+        assert!(v.index_range(10..50).is_sorted_by(|a, b| a >= b));
+    }
+
+    #[test]
+    fn select_nth_unstable() {
+        for &n in &[1, 5, 21, 100, 257] {
+            let expected = sorted(&scrambled(n));
+            for &k in &[0, n / 3, n / 2, n - 1] {
+                let mut v = scrambled(n);
+                {
+                    let mut s = v.index_range_full_mut(..);
+                    let (before, nth, after) = s.select_nth_unstable(k);
+                    assert_eq!(*nth, expected[k]);
+                    assert_eq!(before.len(), k);
+                    assert_eq!(after.len(), n - k - 1);
+                    assert!(before.iter().all(|x| *x <= expected[k]));
+                    assert!(after.iter().all(|x| *x >= expected[k]));
+                }
+                assert_eq!(v[k], expected[k]);
+            }
+        }
+        let mut v = scrambled(30);
+        let max = *v.iter().max().unwrap();
+        assert_eq!(*v.index_range_full_mut(..).select_nth_unstable_by_key(0, |x| 100 - x).1, max);
+    }
+
+    #[test]
+    fn all_equal() {
+        let mut v: VecDeque<u32> = (0..5000).map(|i| i % 2).collect();
+        {
+            let mut s = v.index_range_full_mut(..);
+            let (before, nth, after) = s.select_nth_unstable(2500);
+            assert_eq!(*nth, 1);
+            assert!(before.iter().all(|x| *x <= 1));
+            assert!(after.iter().all(|x| *x == 1));
+        }
+        for x in v.iter_mut() {
+            *x = 7;
+        }
+        assert_eq!(*v.index_range_full_mut(..).select_nth_unstable(1234).1, 7);
+        v.index_range_full_mut(..).sort_unstable();
+        assert!(v.iter().all(|x| *x == 7));
+    }
+
+    struct Bytes(Vec<u32>);
+
+    impl ::std::ops::Index<u8> for Bytes {
+        type Output = u32;
+        fn index(&self, index: u8) -> &u32 {
+            &self.0[index as usize]
+        }
+    }
+
+    impl ::std::ops::IndexMut<u8> for Bytes {
+        fn index_mut(&mut self, index: u8) -> &mut u32 {
+            &mut self.0[index as usize]
+        }
+    }
+
+    impl TakeSlice<u32, u8> for Bytes {
+        fn len(&self) -> u8 {
+            self.0.len() as u8
+        }
+    }
+
+    #[test]
+    fn sort_unstable_small_index() {
+        let mut b = Bytes(scrambled(200).into_iter().collect());
+        let mut expected = b.0.clone();
+        expected.sort();
+        b.index_range_full_mut(..).sort_unstable();
+        assert_eq!(b.0, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn select_nth_out_of_bounds() {
+        let mut v = scrambled(5);
+        v.index_range_full_mut(..).select_nth_unstable(5);
+    }
+}
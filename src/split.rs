@@ -0,0 +1,490 @@
+use core::ops::{Index, IndexMut};
+use core::marker;
+use num_traits::{Zero, One};
+use super::{Idx, Slice, SliceMut, UniqueIndexMut, BoundsPolicy, Checked};
+use search::{find_first, find_last};
+
+impl<'a, K, I, T, P> Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Returns an iterator over sub-slices separated by elements that match `pred`.
+    /// The matched element is not contained in the sub-slices.
+    pub fn split<F>(self, pred: F) -> Split<'a, K, I, T, F, P>
+        where F: FnMut(&T) -> bool
+    {
+        Split {
+            slice: self,
+            pred,
+            finished: false,
+        }
+    }
+
+    /// Returns an iterator over sub-slices separated by elements that match `pred`.
+    /// Unlike `split`, the matched element is kept at the end of the
+    /// preceding sub-slice.
+    pub fn split_inclusive<F>(self, pred: F) -> SplitInclusive<'a, K, I, T, F, P>
+        where F: FnMut(&T) -> bool
+    {
+        SplitInclusive {
+            finished: self.is_empty(),
+            slice: self,
+            pred,
+        }
+    }
+
+    /// Returns an iterator over maximal runs of elements where `pred`
+    /// holds for every pair of consecutive elements.
+    pub fn chunk_by<F>(self, pred: F) -> ChunkBy<'a, K, I, T, F, P>
+        where F: FnMut(&T, &T) -> bool
+    {
+        ChunkBy {
+            slice: self,
+            pred,
+        }
+    }
+
+    /// Returns an iterator over sub-slices separated by elements that match `pred`,
+    /// starting at the end of the slice and working backwards.
+    pub fn rsplit<F>(self, pred: F) -> RSplit<'a, K, I, T, F, P>
+        where F: FnMut(&T) -> bool
+    {
+        RSplit { inner: self.split(pred) }
+    }
+
+    /// Returns an iterator over sub-slices separated by elements that match `pred`,
+    /// limited to returning at most `n` items. The last sub-slice returned
+    /// contains the remainder of the slice.
+    pub fn splitn<F>(self, n: usize, pred: F) -> SplitN<'a, K, I, T, F, P>
+        where F: FnMut(&T) -> bool
+    {
+        SplitN {
+            inner: self.split(pred),
+            count: n,
+        }
+    }
+}
+
+impl<'a, K, I, T, P> SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Returns an iterator over mutable sub-slices separated by elements
+    /// that match `pred`. The matched element is not contained in the sub-slices.
+    pub fn split_mut<F>(self, pred: F) -> SplitMut<'a, K, I, T, F, P>
+        where K: UniqueIndexMut<I>,
+              F: FnMut(&T) -> bool
+    {
+        SplitMut {
+            slice: self,
+            pred,
+            finished: false,
+        }
+    }
+}
+
+/// An iterator over sub-slices separated by elements that match a predicate.
+///
+/// Returned by `Slice::split`.
+pub struct Split<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a, F, P: BoundsPolicy = Checked> {
+    slice: Slice<'a, K, I, T, P>,
+    pred: F,
+    finished: bool,
+}
+
+impl<'a, K, I, T, F, P> Split<'a, K, I, T, F, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    fn finish(&mut self) -> Option<Slice<'a, K, I, T, P>> {
+        if self.finished {
+            None
+        } else {
+            self.finished = true;
+            Some(self.slice)
+        }
+    }
+}
+
+impl<'a, K, I, T, F, P> Iterator for Split<'a, K, I, T, F, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          F: FnMut(&T) -> bool,
+          P: BoundsPolicy
+{
+    type Item = Slice<'a, K, I, T, P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let (start, end) = (self.slice.start, self.slice.start + self.slice.len);
+        match find_first(self.slice.list, start, end, &mut self.pred) {
+            None => self.finish(),
+            Some(i) => {
+                let head = Slice::from_range(self.slice.list, start..i);
+                self.slice = Slice::from_range(self.slice.list, i + One::one()..end);
+                Some(head)
+            }
+        }
+    }
+}
+
+impl<'a, K, I, T, F, P> DoubleEndedIterator for Split<'a, K, I, T, F, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          F: FnMut(&T) -> bool,
+          P: BoundsPolicy
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let (start, end) = (self.slice.start, self.slice.start + self.slice.len);
+        match find_last(self.slice.list, start, end, &mut self.pred) {
+            None => self.finish(),
+            Some(i) => {
+                let tail = Slice::from_range(self.slice.list, i + One::one()..end);
+                self.slice = Slice::from_range(self.slice.list, start..i);
+                Some(tail)
+            }
+        }
+    }
+}
+
+/// An iterator over sub-slices terminated by elements that match a predicate.
+///
+/// Returned by `Slice::split_inclusive`.
+pub struct SplitInclusive<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a, F, P: BoundsPolicy = Checked> {
+    slice: Slice<'a, K, I, T, P>,
+    pred: F,
+    finished: bool,
+}
+
+impl<'a, K, I, T, F, P> Iterator for SplitInclusive<'a, K, I, T, F, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          F: FnMut(&T) -> bool,
+          P: BoundsPolicy
+{
+    type Item = Slice<'a, K, I, T, P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let (start, end) = (self.slice.start, self.slice.start + self.slice.len);
+        let split = match find_first(self.slice.list, start, end, &mut self.pred) {
+            Some(i) => i + One::one(),
+            None => end,
+        };
+        let head = Slice::from_range(self.slice.list, start..split);
+        self.slice = Slice::from_range(self.slice.list, split..end);
+        self.finished = self.slice.is_empty();
+        Some(head)
+    }
+}
+
+impl<'a, K, I, T, F, P> DoubleEndedIterator for SplitInclusive<'a, K, I, T, F, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          F: FnMut(&T) -> bool,
+          P: BoundsPolicy
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        // the last element terminates the final sub-slice
+        // whether or not it matches, so leave it out of the search
+        let (start, end) = (self.slice.start, self.slice.start + self.slice.len);
+        let split = match find_last(self.slice.list, start, end - One::one(), &mut self.pred) {
+            Some(i) => i + One::one(),
+            None => start,
+        };
+        let tail = Slice::from_range(self.slice.list, split..end);
+        self.slice = Slice::from_range(self.slice.list, start..split);
+        self.finished = self.slice.is_empty();
+        Some(tail)
+    }
+}
+
+/// An iterator over runs of elements where consecutive pairs match a predicate.
+///
+/// Returned by `Slice::chunk_by`.
+pub struct ChunkBy<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a, F, P: BoundsPolicy = Checked> {
+    slice: Slice<'a, K, I, T, P>,
+    pred: F,
+}
+
+impl<'a, K, I, T, F, P> Iterator for ChunkBy<'a, K, I, T, F, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          F: FnMut(&T, &T) -> bool,
+          P: BoundsPolicy
+{
+    type Item = Slice<'a, K, I, T, P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            return None;
+        }
+        let list = self.slice.list;
+        let end = self.slice.start + self.slice.len;
+        let mut i = self.slice.start + One::one();
+        while i != end && (self.pred)(&list[i - One::one()], &list[i]) {
+            i = i + One::one();
+        }
+        let (head, tail) = self.slice.split_at(i - self.slice.start);
+        self.slice = tail;
+        Some(head)
+    }
+}
+
+impl<'a, K, I, T, F, P> DoubleEndedIterator for ChunkBy<'a, K, I, T, F, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          F: FnMut(&T, &T) -> bool,
+          P: BoundsPolicy
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            return None;
+        }
+        let list = self.slice.list;
+        let start = self.slice.start;
+        let mut i = start + self.slice.len - One::one();
+        while i != start && (self.pred)(&list[i - One::one()], &list[i]) {
+            i = i - One::one();
+        }
+        let (head, tail) = self.slice.split_at(i - start);
+        self.slice = head;
+        Some(tail)
+    }
+}
+
+/// An iterator over sub-slices separated by elements that match a predicate,
+/// starting from the end of the slice.
+///
+/// Returned by `Slice::rsplit`.
+pub struct RSplit<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a, F, P: BoundsPolicy = Checked> {
+    inner: Split<'a, K, I, T, F, P>,
+}
+
+impl<'a, K, I, T, F, P> Iterator for RSplit<'a, K, I, T, F, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          F: FnMut(&T) -> bool,
+          P: BoundsPolicy
+{
+    type Item = Slice<'a, K, I, T, P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<'a, K, I, T, F, P> DoubleEndedIterator for RSplit<'a, K, I, T, F, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          F: FnMut(&T) -> bool,
+          P: BoundsPolicy
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+/// An iterator over at most `n` sub-slices separated by elements that
+/// match a predicate.
+///
+/// Returned by `Slice::splitn`.
+pub struct SplitN<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a, F, P: BoundsPolicy = Checked> {
+    inner: Split<'a, K, I, T, F, P>,
+    count: usize,
+}
+
+impl<'a, K, I, T, F, P> Iterator for SplitN<'a, K, I, T, F, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          F: FnMut(&T) -> bool,
+          P: BoundsPolicy
+{
+    type Item = Slice<'a, K, I, T, P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count -= 1;
+                self.inner.finish()
+            }
+            _ => {
+                self.count -= 1;
+                self.inner.next()
+            }
+        }
+    }
+}
+
+/// An iterator over mutable sub-slices separated by elements that
+/// match a predicate.
+///
+/// Returned by `SliceMut::split_mut`.
+pub struct SplitMut<'a, K: 'a + ?Sized + IndexMut<I, Output = T>, I: 'a + Idx, T: 'a, F, P: BoundsPolicy = Checked> {
+    slice: SliceMut<'a, K, I, T, P>,
+    pred: F,
+    finished: bool,
+}
+
+impl<'a, K, I, T, F, P> SplitMut<'a, K, I, T, F, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    // The sub-slices handed out never overlap, and `K: ?Sized + UniqueIndexMut`
+    // ensures they can be used at the same time.
+    fn sub_slice(&self, start: I, end: I) -> SliceMut<'a, K, I, T, P> {
+        SliceMut {
+            list: self.slice.list,
+            start,
+            len: end - start,
+            ty: marker::PhantomData,
+        }
+    }
+
+    fn finish(&mut self) -> Option<SliceMut<'a, K, I, T, P>> {
+        if self.finished {
+            None
+        } else {
+            self.finished = true;
+            let (start, end) = (self.slice.start, self.slice.start + self.slice.len);
+            self.slice.len = Zero::zero();
+            Some(self.sub_slice(start, end))
+        }
+    }
+}
+
+impl<'a, K, I, T, F, P> Iterator for SplitMut<'a, K, I, T, F, P>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Idx,
+          F: FnMut(&T) -> bool,
+          P: BoundsPolicy
+{
+    type Item = SliceMut<'a, K, I, T, P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let (start, end) = (self.slice.start, self.slice.start + self.slice.len);
+        match find_first(self.slice.list(), start, end, &mut self.pred) {
+            None => self.finish(),
+            Some(i) => {
+                self.slice.start = i + One::one();
+                self.slice.len = end - self.slice.start;
+                Some(self.sub_slice(start, i))
+            }
+        }
+    }
+}
+
+impl<'a, K, I, T, F, P> DoubleEndedIterator for SplitMut<'a, K, I, T, F, P>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Idx,
+          F: FnMut(&T) -> bool,
+          P: BoundsPolicy
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let (start, end) = (self.slice.start, self.slice.start + self.slice.len);
+        match find_last(self.slice.list(), start, end, &mut self.pred) {
+            None => self.finish(),
+            Some(i) => {
+                self.slice.len = i - start;
+                Some(self.sub_slice(i + One::one(), end))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use {Slice, TakeSlice};
+
+    fn test_vec() -> VecDeque<usize> {
+        vec![1, 0, 2, 3, 0, 4, 0].into_iter().collect()
+    }
+
+    fn collect<'a, It>(iter: It) -> Vec<Vec<usize>>
+        where It: Iterator<Item = Slice<'a, VecDeque<usize>, usize, usize>>
+    {
+        iter.map(|s| s.iter().cloned().collect()).collect()
+    }
+
+    #[test]
+    fn split() {
+        let v = test_vec();
+        assert_eq!(collect(v.index_range(0..7).split(|x| *x == 0)),
+                   vec![vec![1], vec![2, 3], vec![4], vec![]]);
+        assert_eq!(collect(v.index_range(0..6).split(|x| *x == 0).rev()),
+                   vec![vec![4], vec![2, 3], vec![1]]);
+        assert_eq!(collect(v.index_range(2..4).split(|x| *x == 0)), vec![vec![2, 3]]);
+        assert_eq!(collect(v.index_range(0..0).split(|x| *x == 0)), vec![vec![]]);
+    }
+
+    #[test]
+    fn split_inclusive() {
+        let v = test_vec();
+        assert_eq!(collect(v.index_range(0..7).split_inclusive(|x| *x == 0)),
+                   vec![vec![1, 0], vec![2, 3, 0], vec![4, 0]]);
+        assert_eq!(collect(v.index_range(0..6).split_inclusive(|x| *x == 0)),
+                   vec![vec![1, 0], vec![2, 3, 0], vec![4]]);
+        assert_eq!(collect(v.index_range(0..6).split_inclusive(|x| *x == 0).rev()),
+                   vec![vec![4], vec![2, 3, 0], vec![1, 0]]);
+        assert_eq!(collect(v.index_range(0..7).split_inclusive(|x| *x == 0).rev()),
+                   vec![vec![4, 0], vec![2, 3, 0], vec![1, 0]]);
+        assert!(v.index_range(0..0).split_inclusive(|x| *x == 0).next().is_none());
+    }
+
+    #[test]
+    fn chunk_by() {
+        let v: VecDeque<usize> = vec![1, 1, 2, 3, 3, 3, 1].into_iter().collect();
+        assert_eq!(collect(v.index_range(0..7).chunk_by(|a, b| a == b)),
+                   vec![vec![1, 1], vec![2], vec![3, 3, 3], vec![1]]);
+        assert_eq!(collect(v.index_range(0..7).chunk_by(|a, b| a <= b).rev()),
+                   vec![vec![1], vec![1, 1, 2, 3, 3, 3]]);
+        assert!(v.index_range(0..0).chunk_by(|a, b| a == b).next().is_none());
+    }
+
+    #[test]
+    fn rsplit_and_splitn() {
+        let v = test_vec();
+        assert_eq!(collect(v.index_range(0..6).rsplit(|x| *x == 0)),
+                   vec![vec![4], vec![2, 3], vec![1]]);
+        assert_eq!(collect(v.index_range(0..6).splitn(2, |x| *x == 0)),
+                   vec![vec![1], vec![2, 3, 0, 4]]);
+        assert_eq!(collect(v.index_range(0..6).splitn(0, |x| *x == 0)).len(), 0);
+    }
+
+    #[test]
+    fn split_mut() {
+        let mut v = test_vec();
+        let mut parts: Vec<_> = v.index_range_mut(0..6).split_mut(|x| *x == 0).collect();
+        assert_eq!(parts.len(), 3);
+        for part in &mut parts {
+            let len = part.len();
+            part[len - 1] *= 10;
+        }
+        drop(parts);
+        assert_eq!(v, vec![10, 0, 2, 30, 0, 40, 0]);
+        let last = v.index_range_mut(0..6).split_mut(|x| *x == 0).next_back().unwrap();
+        assert_eq!(last.len(), 1);
+    }
+}
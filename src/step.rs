@@ -0,0 +1,218 @@
+use core::fmt::Debug;
+use core::ops::{Add, Sub};
+use num_traits::{Zero, One, NumCast};
+
+/// The stepping operations which walking over a range of indices needs:
+/// a starting point, the next index, and the distance between two indices.
+///
+/// This is all that slicing, indexing and iterating need, so index newtypes
+/// which only implement `Step` can be used with `TakeSlice`, `Slice`,
+/// `SliceMut` and their iterators. The views' other methods, like `chunks`
+/// or `sort`, do arithmetic on indices and need the full `Idx`.
+///
+/// `Step` is implemented automatically for every integer-like type.
+/// Index newtypes can get it from the `index_newtype!` macro, whose
+/// `forward` jumps ahead through the inner integer in `O(1)`.
+pub trait Step: Copy + Ord + Debug {
+    /// The first index.
+    fn zero() -> Self;
+
+    /// The index after `self`.
+    fn successor(self) -> Self;
+
+    /// The index after `self`, or `None` if it can't be represented.
+    ///
+    /// The default assumes `successor` never overflows,
+    /// so bounded index types should override it.
+    #[inline]
+    fn checked_successor(self) -> Option<Self> {
+        Some(self.successor())
+    }
+
+    /// The number of steps from `start` to `end`,
+    /// or `None` if it doesn't fit in a `usize`.
+    fn distance(start: Self, end: Self) -> Option<usize>;
+
+    /// The index `n` steps after `self`.
+    ///
+    /// The default calls `successor` `n` times. Views offset every index
+    /// with `forward`, so this makes indexing and slicing `O(index)`;
+    /// types which can jump ahead directly should override it.
+    #[inline]
+    fn forward(self, n: usize) -> Self {
+        let mut index = self;
+        for _ in 0..n {
+            index = index.successor();
+        }
+        index
+    }
+}
+
+impl<T> Step for T
+    where T: Add<T, Output = T> + Sub<T, Output = T> + Zero + One + Ord + NumCast + Copy + Debug
+{
+    #[inline]
+    fn zero() -> Self {
+        Zero::zero()
+    }
+
+    #[inline]
+    fn successor(self) -> Self {
+        self + One::one()
+    }
+
+    #[inline]
+    fn checked_successor(self) -> Option<Self> {
+        match self.to_u128() {
+            Some(n) => n.checked_add(1).and_then(NumCast::from),
+            // negative, so adding one can't overflow
+            None => Some(self + One::one()),
+        }
+    }
+
+    #[inline]
+    fn distance(start: Self, end: Self) -> Option<usize> {
+        (end - start).to_usize()
+    }
+
+    #[inline]
+    fn forward(self, n: usize) -> Self {
+        match <T as NumCast>::from(n) {
+            Some(n) => self + n,
+            None => panic!("Index overflow: {:?} + {}", self, n),
+        }
+    }
+}
+
+/// Implements `Step` for a newtype around an integer, such as
+/// `struct RowId(u32)`, so that it can be used to slice and iterate
+/// strongly-typed collections without exposing the raw integer.
+///
+/// The newtype must derive (or implement) `Clone`, `Copy`, `Debug`,
+/// `PartialEq`, `Eq`, `PartialOrd` and `Ord` itself. No arithmetic is
+/// implemented for it, so the methods which need `Idx` aren't available.
+///
+/// ```
+/// #[macro_use]
+/// extern crate owned_slice;
+///
+/// use std::ops::{Index, IndexMut};
+/// use owned_slice::TakeSlice;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// struct RowId(u32);
+/// index_newtype!(RowId(u32));
+///
+/// struct Rows(Vec<&'static str>);
+///
+/// impl Index<RowId> for Rows {
+///     type Output = &'static str;
+///     fn index(&self, id: RowId) -> &&'static str {
+///         &self.0[id.0 as usize]
+///     }
+/// }
+///
+/// impl IndexMut<RowId> for Rows {
+///     fn index_mut(&mut self, id: RowId) -> &mut &'static str {
+///         &mut self.0[id.0 as usize]
+///     }
+/// }
+///
+/// impl TakeSlice<&'static str, RowId> for Rows {
+///     fn len(&self) -> RowId {
+///         RowId(self.0.len() as u32)
+///     }
+/// }
+///
+/// # fn main() {
+/// let mut rows = Rows(vec!["a", "b", "c", "d"]);
+/// let slice = rows.index_range(RowId(1)..RowId(3));
+/// assert_eq!(slice.len(), RowId(2));
+/// assert_eq!(slice[RowId(0)], "b");
+/// assert_eq!(slice.iter().rev().collect::<Vec<_>>(), [&"c", &"b"]);
+/// rows.index_range_from_mut(RowId(2)..)[RowId(1)] = "e";
+/// assert_eq!(rows.0, ["a", "b", "c", "e"]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! index_newtype {
+    ($name:ident($inner:ty)) => {
+        impl $crate::Step for $name {
+            #[inline]
+            fn zero() -> $name {
+                $name(<$inner as $crate::Step>::zero())
+            }
+
+            #[inline]
+            fn successor(self) -> $name {
+                $name($crate::Step::successor(self.0))
+            }
+
+            #[inline]
+            fn checked_successor(self) -> Option<$name> {
+                $crate::Step::checked_successor(self.0).map($name)
+            }
+
+            #[inline]
+            fn distance(start: $name, end: $name) -> Option<usize> {
+                <$inner as $crate::Step>::distance(start.0, end.0)
+            }
+
+            #[inline]
+            fn forward(self, n: usize) -> $name {
+                $name($crate::Step::forward(self.0, n))
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::ops::Index;
+    use {Step, TakeSlice, Clamped};
+
+    /// An index with nothing but the required `Step` methods.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Pos(u8);
+
+    impl Step for Pos {
+        fn zero() -> Pos {
+            Pos(0)
+        }
+        fn successor(self) -> Pos {
+            Pos(self.0 + 1)
+        }
+        fn distance(start: Pos, end: Pos) -> Option<usize> {
+            Some((end.0 - start.0) as usize)
+        }
+    }
+
+    struct Cells(VecDeque<char>);
+
+    impl Index<Pos> for Cells {
+        type Output = char;
+        fn index(&self, pos: Pos) -> &char {
+            &self.0[pos.0 as usize]
+        }
+    }
+
+    impl TakeSlice<char, Pos> for Cells {
+        fn len(&self) -> Pos {
+            Pos(self.0.len() as u8)
+        }
+    }
+
+    #[test]
+    fn step_only_index() {
+        let cells = Cells("abcdef".chars().collect());
+        let slice = cells.take_slice(Pos(1)..=Pos(4));
+        assert_eq!(slice.len(), Pos(4));
+        assert_eq!(slice[Pos(2)], 'd');
+        assert_eq!(slice.last(), Some(&'e'));
+        assert_eq!(slice.iter().rev().collect::<String>(), "edcb");
+        assert_eq!(slice.subslice(Pos(1)..Pos(3)).iter().collect::<String>(), "cd");
+        assert_eq!(slice.with_policy::<Clamped>()[Pos(9)], 'e');
+        assert!(cells.try_index_range(Pos(2)..Pos(7)).is_err());
+    }
+}
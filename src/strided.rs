@@ -0,0 +1,539 @@
+use core::cmp;
+use core::fmt::{self, Debug};
+use core::marker;
+use core::ops::{Index, IndexMut, Range};
+use num_traits::{Zero, One};
+use iter::remaining;
+use util::{to_usize, from_usize, assert_len_fits};
+use {BoundsPolicy, Idx, Iter, IterMut, Slice, SliceMut, TakeSlice, UniqueIndexMut};
+
+/// An immutable view of every `step`-th element in a range of a container.
+///
+/// Created with `TakeSlice::slice_step`. Index `i` of the view maps to
+/// `start + i * step` in the container.
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use owned_slice::TakeSlice;
+///
+/// // left and right samples, interleaved
+/// let samples: VecDeque<i16> = vec![1, -1, 2, -2, 3, -3].into_iter().collect();
+/// let right = samples.slice_step(1.., 2);
+/// assert_eq!(right.len(), 3);
+/// assert_eq!(right[2], -3);
+/// assert_eq!(right.iter().sum::<i16>(), -6);
+/// ```
+pub struct StridedSlice<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a> {
+    list: &'a K,
+    start: I,
+    step: I,
+    len: I,
+    ty: marker::PhantomData<T>,
+}
+
+/// A mutable view of every `step`-th element in a range of a container.
+///
+/// Created with `TakeSlice::slice_step_mut`.
+pub struct StridedSliceMut<'a, K: 'a + ?Sized + IndexMut<I, Output = T>, I: 'a + Idx, T: 'a> {
+    // a raw pointer rather than `&'a mut K`, so that `SliceMut::deinterleave`
+    // can hand out one view per channel over the same container. Like
+    // `SliceMut`, this keeps the channels on the thread that created them.
+    list: *mut K,
+    start: I,
+    step: I,
+    len: I,
+    ty: marker::PhantomData<(&'a mut K, T)>,
+}
+
+/// An iterator over the channels of interleaved data, created with
+/// `Slice::deinterleave`.
+pub struct Deinterleave<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a> {
+    slice: Slice<'a, K, I, T>,
+    cur: I,
+    channels: I,
+}
+
+/// An iterator over mutable views of the channels of interleaved data,
+/// created with `SliceMut::deinterleave`.
+pub struct DeinterleaveMut<'a, K: 'a + ?Sized + UniqueIndexMut<I, Output = T>, I: 'a + Idx, T: 'a> {
+    slice: SliceMut<'a, K, I, T>,
+    cur: I,
+    channels: I,
+}
+
+/// Returns how many elements of `range` are visited when stepping by `step`.
+fn strided_len<I: Idx>(range: &Range<I>, step: I) -> I {
+    if step == Zero::zero() {
+        panic!("step must not be zero");
+    }
+    let (span, step) = (to_usize(range.end - range.start), to_usize(step));
+    from_usize(span.div_ceil(step))
+}
+
+impl<'a, K, I, T> StridedSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    /// Creates a view of every `step`-th element of `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    pub fn new(list: &'a K, index: Range<I>, step: I) -> StridedSlice<'a, K, I, T> {
+        StridedSlice {
+            list,
+            start: index.start,
+            len: strided_len(&index, step),
+            step,
+            ty: marker::PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the view.
+    #[inline]
+    pub fn len(&self) -> I {
+        self.len
+    }
+
+    /// Returns `true` if the view has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == Zero::zero()
+    }
+
+    /// Returns the distance between consecutive elements in the container.
+    #[inline]
+    pub fn step(&self) -> I {
+        self.step
+    }
+
+    /// Returns a reference to the element at `index`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: I) -> Option<&'a T> {
+        if index < self.len {
+            Some(&self.list[self.start + index * self.step])
+        } else {
+            None
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, Self, I, T> {
+        self.index_range_full(..).iter()
+    }
+}
+
+impl<'a, K, I, T> StridedSliceMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx
+{
+    /// Creates a mutable view of every `step`-th element of `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    pub fn new(list: &'a mut K, index: Range<I>, step: I) -> StridedSliceMut<'a, K, I, T> {
+        StridedSliceMut {
+            list: list as *mut K,
+            start: index.start,
+            len: strided_len(&index, step),
+            step,
+            ty: marker::PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the view.
+    #[inline]
+    pub fn len(&self) -> I {
+        self.len
+    }
+
+    /// Returns `true` if the view has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == Zero::zero()
+    }
+
+    /// Returns the distance between consecutive elements in the container.
+    #[inline]
+    pub fn step(&self) -> I {
+        self.step
+    }
+
+    /// Returns a reference to the element at `index`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: I) -> Option<&T> {
+        self.as_strided().get(index)
+    }
+
+    /// Returns a mutable reference to the element at `index`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: I) -> Option<&mut T> {
+        if index < self.len {
+            let start = self.start;
+            let step = self.step;
+            Some(&mut self.list_mut()[start + index * step])
+        } else {
+            None
+        }
+    }
+
+    /// Views the same elements through an immutable strided slice.
+    pub fn as_strided(&self) -> StridedSlice<'_, K, I, T> {
+        StridedSlice {
+            list: self.list(),
+            start: self.start,
+            step: self.step,
+            len: self.len,
+            ty: marker::PhantomData,
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, Self, I, T> {
+        self.index_range_full(..).iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, Self, I, T>
+        where K: UniqueIndexMut<I>
+    {
+        self.index_range_full_mut(..).iter_mut()
+    }
+
+    #[inline]
+    fn list(&self) -> &K {
+        unsafe { &*self.list }
+    }
+
+    #[inline]
+    fn list_mut(&mut self) -> &mut K {
+        unsafe { &mut *self.list }
+    }
+}
+
+/// Returns the range of a slice of length `len` which holds channel
+/// `channel`, and the number of samples in it.
+#[inline]
+fn channel<I: Idx>(channel: I, channels: I, len: I) -> (I, I) {
+    let offset = cmp::min(channel, len);
+    (offset, strided_len(&(offset..len), channels))
+}
+
+impl<'a, K, I, T, P> Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Splits interleaved data into one strided view per channel,
+    /// so that channel `c` holds elements `c`, `c + channels`, `c + 2 * channels`...
+    ///
+    /// If the length is not a multiple of `channels`, the trailing
+    /// channels of the last frame are one element shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channels` is zero.
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use owned_slice::TakeSlice;
+    ///
+    /// let stereo: VecDeque<f32> = vec![0.5, -0.5, 0.25, -0.25].into_iter().collect();
+    /// let mut channels = stereo.index_range_full(..).deinterleave(2);
+    /// let (left, right) = (channels.next().unwrap(), channels.next().unwrap());
+    /// assert_eq!(left.iter().sum::<f32>(), 0.75);
+    /// assert_eq!(right[1], -0.25);
+    /// ```
+    pub fn deinterleave(self, channels: I) -> Deinterleave<'a, K, I, T> {
+        if channels == Zero::zero() {
+            panic!("number of channels must not be zero");
+        }
+        assert_len_fits(channels);
+        Deinterleave {
+            slice: self.with_policy(),
+            cur: Zero::zero(),
+            channels,
+        }
+    }
+}
+
+impl<'a, K, I, T, P> SliceMut<'a, K, I, T, P>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Splits interleaved data into one mutable strided view per channel.
+    ///
+    /// The channels never share an element, so all of them can be
+    /// modified at the same time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channels` is zero.
+    pub fn deinterleave(self, channels: I) -> DeinterleaveMut<'a, K, I, T> {
+        if channels == Zero::zero() {
+            panic!("number of channels must not be zero");
+        }
+        assert_len_fits(channels);
+        DeinterleaveMut {
+            slice: self.with_policy(),
+            cur: Zero::zero(),
+            channels,
+        }
+    }
+}
+
+impl<'a, K, I, T> Iterator for Deinterleave<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    type Item = StridedSlice<'a, K, I, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cur == self.channels {
+            return None;
+        }
+        let (offset, len) = channel(self.cur, self.channels, self.slice.len);
+        self.cur = self.cur + One::one();
+        Some(StridedSlice {
+            list: self.slice.list,
+            start: self.slice.start + offset,
+            step: self.channels,
+            len,
+            ty: marker::PhantomData,
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        remaining(self.cur, self.channels)
+    }
+}
+
+impl<'a, K, I, T> ExactSizeIterator for Deinterleave<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+}
+
+impl<'a, K, I, T> Iterator for DeinterleaveMut<'a, K, I, T>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Idx
+{
+    type Item = StridedSliceMut<'a, K, I, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cur == self.channels {
+            return None;
+        }
+        let (offset, len) = channel(self.cur, self.channels, self.slice.len);
+        self.cur = self.cur + One::one();
+        // each channel is handed out once, and channels never overlap,
+        // so by the `UniqueIndexMut` contract the views never alias
+        Some(StridedSliceMut {
+            list: self.slice.list,
+            start: self.slice.start + offset,
+            step: self.channels,
+            len,
+            ty: marker::PhantomData,
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        remaining(self.cur, self.channels)
+    }
+}
+
+impl<'a, K, I, T> ExactSizeIterator for DeinterleaveMut<'a, K, I, T>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Idx
+{
+}
+
+impl<'a, K, I, T> Clone for StridedSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, K, I, T> Copy for StridedSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+}
+
+impl<'a, K, I, T> Index<I> for StridedSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: I) -> &T {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, self.len),
+        }
+    }
+}
+
+impl<'a, K, I, T> Index<I> for StridedSliceMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: I) -> &T {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, self.len),
+        }
+    }
+}
+
+impl<'a, K, I, T> IndexMut<I> for StridedSliceMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx
+{
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut T {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, len),
+        }
+    }
+}
+
+impl<'a, K, I, T> TakeSlice<T, I> for StridedSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    fn len(&self) -> I {
+        self.len
+    }
+}
+
+impl<'a, K, I, T> TakeSlice<T, I> for StridedSliceMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx
+{
+    fn len(&self) -> I {
+        self.len
+    }
+}
+
+// distinct indices of the view map to distinct indices of the container,
+// since the step is never zero
+unsafe impl<'a, K, I, T> UniqueIndexMut<I> for StridedSliceMut<'a, K, I, T>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Idx
+{
+}
+
+impl<'a, K, I, T> Debug for StridedSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          T: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K, I, T> Debug for StridedSliceMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          T: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_strided().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use TakeSlice;
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..10).collect()
+    }
+
+    #[test]
+    fn strided() {
+        let v = test_vec();
+        let s = v.slice_step(1..8, 3);
+        assert_eq!(s.len(), 3);
+        assert_eq!(format!("{:?}", s), "[1, 4, 7]");
+        assert_eq!(s.get(3), None);
+        assert_eq!(v.slice_step(.., 4).index_range(1..3), [4, 8]);
+        assert!(v.slice_step(5..5, 2).is_empty());
+    }
+
+    #[test]
+    fn strided_mut() {
+        let mut v = test_vec();
+        {
+            let mut evens = v.slice_step_mut(.., 2);
+            for x in evens.iter_mut() {
+                *x = 0;
+            }
+            evens.index_range_mut(1..4).reverse();
+        }
+        assert_eq!(v, [0, 1, 0, 3, 0, 5, 0, 7, 0, 9]);
+        let mut odds = v.slice_step_mut(1.., 2);
+        odds.index_range_full_mut(..).sort_unstable_by(|a, b| b.cmp(a));
+        odds[0] += 1;
+        assert_eq!(format!("{:?}", odds), "[10, 7, 5, 3, 1]");
+    }
+
+    #[test]
+    fn deinterleave() {
+        let v = test_vec();
+        let channels: Vec<_> = v.index_range(1..9).deinterleave(3).collect();
+        assert_eq!(format!("{:?}", channels), "[[1, 4, 7], [2, 5, 8], [3, 6]]");
+        assert_eq!(v.index_range_to(..2).deinterleave(4).map(|c| c.len()).collect::<Vec<_>>(), [1, 1, 0, 0]);
+    }
+
+    #[test]
+    fn deinterleave_mut() {
+        let mut v = test_vec();
+        {
+            let mut channels = v.index_range_full_mut(..).deinterleave(2);
+            let (mut left, mut right) = (channels.next().unwrap(), channels.next().unwrap());
+            assert!(channels.next().is_none());
+            for (l, r) in left.iter_mut().zip(right.iter_mut()) {
+                std::mem::swap(l, r);
+            }
+            right[0] = 100;
+        }
+        assert_eq!(v, [1, 100, 3, 2, 5, 4, 7, 6, 9, 8]);
+    }
+
+    #[test]
+    fn channels_at_once() {
+        let mut v = test_vec();
+        {
+            let mut channels = v.index_range_full_mut(..).deinterleave(2);
+            let (mut evens, mut odds) = (channels.next().unwrap(), channels.next().unwrap());
+            for (x, y) in evens.iter_mut().zip(odds.iter_mut()) {
+                *x *= 10;
+                *y += 100;
+            }
+        }
+        assert_eq!(v, [0, 101, 20, 103, 40, 105, 60, 107, 80, 109]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_step() {
+        test_vec().slice_step(.., 0);
+    }
+}
@@ -0,0 +1,346 @@
+use core::marker;
+use core::ops::{Deref, DerefMut, Index, IndexMut, Range};
+use num_traits::Zero;
+use std::sync::{LockResult, Mutex, PoisonError, RwLock};
+use util::assert_in_bounds;
+use {Idx, Iter, Slice, SliceMut, TakeSlice};
+
+/// A window into a container behind a `Mutex`.
+///
+/// `LockedSlice` is `Copy` and can be split into disjoint regions which are
+/// handed to different threads. The mutex is locked for each operation, or
+/// for as long as the guard returned by `lock` is held.
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use std::sync::Mutex;
+/// use std::thread;
+/// use owned_slice::LockedSlice;
+///
+/// let deque: Mutex<VecDeque<u32>> = Mutex::new((0..8).collect());
+/// let (left, right) = LockedSlice::new(&deque, 0..8).split_at(4);
+/// thread::scope(|s| {
+///     s.spawn(|| left.with_mut(|mut w| w.fill(1)));
+///     s.spawn(|| right.lock().unwrap()[0] = 9);
+/// });
+/// assert_eq!(*deque.lock().unwrap(), [1, 1, 1, 1, 9, 5, 6, 7]);
+/// ```
+pub struct LockedSlice<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a> {
+    lock: &'a Mutex<K>,
+    start: I,
+    len: I,
+    ty: marker::PhantomData<fn() -> T>,
+}
+
+/// A window into a container behind a `RwLock`.
+///
+/// Works like `LockedSlice`, but `read` and `with` only take a shared lock.
+pub struct RwLockedSlice<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a> {
+    lock: &'a RwLock<K>,
+    start: I,
+    len: I,
+    ty: marker::PhantomData<fn() -> T>,
+}
+
+/// Keeps a container locked while giving access to a window of it.
+///
+/// `G` is the lock's guard, such as a `MutexGuard` or `RwLockReadGuard`.
+pub struct SliceGuard<G, I: Idx, T> {
+    guard: G,
+    start: I,
+    len: I,
+    ty: marker::PhantomData<fn() -> T>,
+}
+
+fn guard_result<G, I, T>(result: LockResult<G>, range: Range<I>) -> LockResult<SliceGuard<G, I, T>>
+    where I: Idx
+{
+    let wrap = |guard| SliceGuard {
+        guard,
+        start: range.start,
+        len: range.end - range.start,
+        ty: marker::PhantomData,
+    };
+    match result {
+        Ok(guard) => Ok(wrap(guard)),
+        Err(e) => Err(PoisonError::new(wrap(e.into_inner()))),
+    }
+}
+
+macro_rules! impl_locked_common {
+    ($name:ident, $lock:ident, $borrow:ident) => {
+        impl<'a, K, I, T> Clone for $name<'a, K, I, T>
+            where K: ?Sized + Index<I, Output = T>,
+                  I: Idx
+        {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<'a, K, I, T> Copy for $name<'a, K, I, T>
+            where K: ?Sized + Index<I, Output = T>,
+                  I: Idx
+        {
+        }
+
+        impl<'a, K, I, T> $name<'a, K, I, T>
+            where K: ?Sized + TakeSlice<T, I>,
+                  I: Idx
+        {
+            /// Creates a window of the locked container covering `index`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is out of bounds, or if the lock is poisoned.
+            pub fn new(lock: &'a $lock<K>, index: Range<I>) -> $name<'a, K, I, T> {
+                assert_in_bounds(&index, lock.$borrow().unwrap().len());
+                $name {
+                    lock,
+                    start: index.start,
+                    len: index.end - index.start,
+                    ty: marker::PhantomData,
+                }
+            }
+        }
+
+        impl<'a, K, I, T> $name<'a, K, I, T>
+            where K: ?Sized + Index<I, Output = T>,
+                  I: Idx
+        {
+            /// Returns the number of elements in the slice.
+            #[inline]
+            pub fn len(&self) -> I {
+                self.len
+            }
+
+            /// Returns `true` if the slice has a length of zero.
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.len == Zero::zero()
+            }
+
+            /// Narrows the window to `range`, which is relative to this slice.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `range` is out of the bounds of this slice.
+            pub fn subslice(&self, range: Range<I>) -> $name<'a, K, I, T> {
+                assert_in_bounds(&range, self.len);
+                $name {
+                    lock: self.lock,
+                    start: self.start + range.start,
+                    len: range.end - range.start,
+                    ty: marker::PhantomData,
+                }
+            }
+
+            /// Divides the window into two at an index.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `mid > len`.
+            pub fn split_at(self, mid: I) -> ($name<'a, K, I, T>, $name<'a, K, I, T>) {
+                assert_in_bounds(&(Zero::zero()..mid), self.len);
+                (self.subslice(Zero::zero()..mid), self.subslice(mid..self.len))
+            }
+
+            fn range(&self) -> Range<I> {
+                self.start..self.start + self.len
+            }
+        }
+    }
+}
+
+impl_locked_common!(LockedSlice, Mutex, lock);
+impl_locked_common!(RwLockedSlice, RwLock, read);
+
+impl<'a, K, I, T> LockedSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    /// Locks the mutex, returning a guard which gives access to the window.
+    pub fn lock(&self) -> LockResult<SliceGuard<::std::sync::MutexGuard<'a, K>, I, T>> {
+        guard_result(self.lock.lock(), self.range())
+    }
+
+    /// Calls `f` with an immutable view of the window while holding the lock.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutex is poisoned.
+    pub fn with<R, F>(&self, f: F) -> R
+        where F: FnOnce(Slice<K, I, T>) -> R
+    {
+        f(self.lock().unwrap().as_slice())
+    }
+}
+
+impl<'a, K, I, T> LockedSlice<'a, K, I, T>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx
+{
+    /// Calls `f` with a mutable view of the window while holding the lock.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutex is poisoned.
+    pub fn with_mut<R, F>(&self, f: F) -> R
+        where F: FnOnce(SliceMut<K, I, T>) -> R
+    {
+        f(self.lock().unwrap().as_slice_mut())
+    }
+}
+
+impl<'a, K, I, T> RwLockedSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    /// Takes a shared lock, returning a guard which gives read access to the window.
+    pub fn read(&self) -> LockResult<SliceGuard<::std::sync::RwLockReadGuard<'a, K>, I, T>> {
+        guard_result(self.lock.read(), self.range())
+    }
+
+    /// Takes an exclusive lock, returning a guard which gives access to the window.
+    pub fn write(&self) -> LockResult<SliceGuard<::std::sync::RwLockWriteGuard<'a, K>, I, T>> {
+        guard_result(self.lock.write(), self.range())
+    }
+
+    /// Calls `f` with an immutable view of the window while holding a shared lock.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock is poisoned.
+    pub fn with<R, F>(&self, f: F) -> R
+        where F: FnOnce(Slice<K, I, T>) -> R
+    {
+        f(self.read().unwrap().as_slice())
+    }
+}
+
+impl<'a, K, I, T> RwLockedSlice<'a, K, I, T>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx
+{
+    /// Calls `f` with a mutable view of the window while holding an exclusive lock.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock is poisoned.
+    pub fn with_mut<R, F>(&self, f: F) -> R
+        where F: FnOnce(SliceMut<K, I, T>) -> R
+    {
+        f(self.write().unwrap().as_slice_mut())
+    }
+}
+
+impl<G, I, T> SliceGuard<G, I, T>
+    where G: Deref,
+          G::Target: Index<I, Output = T>,
+          I: Idx
+{
+    /// Returns the number of elements in the slice.
+    #[inline]
+    pub fn len(&self) -> I {
+        self.len
+    }
+
+    /// Returns `true` if the slice has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == Zero::zero()
+    }
+
+    /// Views the locked window through an immutable slice.
+    pub fn as_slice(&self) -> Slice<'_, G::Target, I, T> {
+        Slice::new(&*self.guard, self.start..self.start + self.len)
+    }
+
+    pub fn iter(&self) -> Iter<'_, G::Target, I, T> {
+        self.as_slice().iter()
+    }
+}
+
+impl<G, I, T> SliceGuard<G, I, T>
+    where G: DerefMut,
+          G::Target: IndexMut<I, Output = T>,
+          I: Idx
+{
+    /// Views the locked window through a mutable slice.
+    pub fn as_slice_mut(&mut self) -> SliceMut<'_, G::Target, I, T> {
+        let range = self.start..self.start + self.len;
+        SliceMut::new(&mut *self.guard, range)
+    }
+}
+
+impl<G, I, T> Index<I> for SliceGuard<G, I, T>
+    where G: Deref,
+          G::Target: Index<I, Output = T>,
+          I: Idx
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: I) -> &T {
+        if index >= self.len {
+            panic!("Index out of bounds: {:?} >= {:?}", index, self.len);
+        }
+        &self.guard[self.start + index]
+    }
+}
+
+impl<G, I, T> IndexMut<I> for SliceGuard<G, I, T>
+    where G: DerefMut,
+          G::Target: IndexMut<I, Output = T>,
+          I: Idx
+{
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut T {
+        if index >= self.len {
+            panic!("Index out of bounds: {:?} >= {:?}", index, self.len);
+        }
+        let start = self.start;
+        &mut self.guard[start + index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::sync::{Mutex, RwLock};
+    use std::thread;
+    use {LockedSlice, RwLockedSlice};
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..10).collect()
+    }
+
+    #[test]
+    fn disjoint_regions() {
+        let v = Mutex::new(test_vec());
+        let whole = LockedSlice::new(&v, 0..10);
+        let (a, b) = whole.split_at(5);
+        thread::scope(|s| {
+            s.spawn(|| a.with_mut(|mut a| a.reverse()));
+            s.spawn(|| {
+                let mut guard = b.subslice(1..3).lock().unwrap();
+                guard[0] = 0;
+                guard[1] = 0;
+            });
+        });
+        assert_eq!(whole.with(|s| s.to_vec()), [4, 3, 2, 1, 0, 5, 0, 0, 8, 9]);
+    }
+
+    #[test]
+    fn read_write_locks() {
+        let v = RwLock::new(test_vec());
+        let s = RwLockedSlice::new(&v, 2..6);
+        {
+            let (r1, r2) = (s.read().unwrap(), s.read().unwrap());
+            assert_eq!(r1.iter().sum::<usize>(), r2.as_slice().iter().sum());
+        }
+        s.write().unwrap()[3] = 0;
+        s.with_mut(|mut s| s[0] += 1);
+        assert_eq!(s.with(|s| s.to_vec()), [3, 3, 4, 0]);
+    }
+}
@@ -0,0 +1,36 @@
+use tinyvec::{Array, ArrayVec, TinyVec};
+use super::TakeSlice;
+
+// Neither type is `UniqueIndexMut`: `index_mut` goes through a slice of all
+// the elements, which may even be stored inline. Wrap them in an
+// `IndexedSlice` to split or iterate them mutably.
+impl<A: Array> TakeSlice<A::Item, usize> for ArrayVec<A> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<A: Array> TakeSlice<A::Item, usize> for TinyVec<A> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tinyvec::{ArrayVec, TinyVec};
+    use {TakeSlice, IndexedSlice};
+
+    #[test]
+    fn take_slice() {
+        let mut array: ArrayVec<[u32; 8]> = (0..5).collect();
+        let mut tiny: TinyVec<[u32; 2]> = (0..5).collect();
+        assert!(tiny.is_heap());
+        assert_eq!(array.index_range(1..4), [1, 2, 3]);
+        array.index_range_mut(0..5).map_in_place(|x| 4 - x);
+        IndexedSlice::new(&mut tiny).index_range_mut(0..5).reverse();
+        assert_eq!(&array[..], &[4, 3, 2, 1, 0]);
+        assert_eq!(&tiny[..], &array[..]);
+        assert!(array.try_index_range(0..8).is_err());
+    }
+}
@@ -1,26 +1,219 @@
-use num_traits::Zero;
-use super::Idx;
-use std::ops::Range;
+use num_traits::{Zero, NumCast};
+use super::{Idx, Step, UniqueIndexMut};
+use error::{check_in_bounds, OutOfBounds};
+use core::cmp;
+#[cfg(any(feature = "std", feature = "bytes"))]
+use alloc::collections::VecDeque;
+use core::ops::{Bound, Range, RangeBounds};
 
 #[cfg(not(feature = "nightly"))]
 #[inline(always)]
-#[cfg_attr(feature = "clippy", allow(inline_always))]
+#[allow(clippy::inline_always)]
 pub fn unlikely(x: bool) -> bool {
     x
 }
 
 #[cfg(feature = "nightly")]
 #[inline(always)]
-#[cfg_attr(feature = "clippy", allow(inline_always))]
+#[allow(clippy::inline_always)]
 pub fn unlikely(x: bool) -> bool {
-    unsafe { ::std::intrinsics::unlikely(x) }
+    unsafe { ::core::intrinsics::unlikely(x) }
 }
 
 #[inline]
-pub fn assert_in_bounds<I: Idx>(index: &Range<I>, len: I) {
-    if unlikely(index.end > len) {
-        panic!("Range out of bounds: {:?} is not a subset of {:?}",
-               index,
-               Zero::zero()..len);
+pub fn assert_in_bounds<I: Step>(index: &Range<I>, len: I) {
+    if let Err(e) = check_in_bounds(index, len) {
+        panic!("{}", e);
+    }
+}
+
+/// The number of steps from zero to `index`, i.e. `index` as a `usize`.
+#[inline]
+pub fn steps<I: Step>(index: I) -> usize {
+    match Step::distance(Step::zero(), index) {
+        Some(n) => n,
+        None => panic!("Index out of range: {:?}", index),
+    }
+}
+
+/// Panics unless `len` fits in a `usize`, so that
+/// iterators over that many items can report their exact length.
+#[inline]
+pub fn assert_len_fits<I: Step>(len: I) {
+    if Step::distance(I::zero(), len).is_none() {
+        panic!("Length doesn't fit in a usize: {:?}", len);
+    }
+}
+
+/// `start + offset`, for indices which only implement `Step`.
+#[inline]
+pub fn offset<I: Step>(start: I, offset: I) -> I {
+    start.forward(steps(offset))
+}
+
+/// `end - start`, for indices which only implement `Step`.
+/// `start` must not be greater than `end`.
+#[inline]
+pub fn span<I: Step>(start: I, end: I) -> I {
+    match Step::distance(start, end) {
+        Some(n) => I::zero().forward(n),
+        None => panic!("Index out of range: {:?} - {:?}", end, start),
+    }
+}
+
+/// `index - 1`, for indices which only implement `Step`.
+/// `index` must be greater than zero.
+#[inline]
+pub fn predecessor<I: Step>(index: I) -> I {
+    I::zero().forward(steps(index) - 1)
+}
+
+/// Resolves any kind of range into a half-open `Range`,
+/// using `len` as the end of unbounded ranges.
+///
+/// Bounds which overflow the index type once made exclusive, like the end of
+/// `..=u8::MAX`, saturate instead, and the flag is set. Such a range can never
+/// be in bounds, since `len` fits in the index type.
+#[inline]
+fn resolve_range<I: Step, R: RangeBounds<I>>(range: &R, len: I) -> (Range<I>, bool) {
+    let mut overflowed = false;
+    let mut after = |index: I| match index.checked_successor() {
+        Some(next) => next,
+        None => {
+            overflowed = true;
+            index
+        }
+    };
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => after(start),
+        Bound::Unbounded => I::zero(),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => after(end),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    (start..end, overflowed)
+}
+
+/// Resolves any kind of range into a half-open `Range`,
+/// using `len` as the end of unbounded ranges.
+/// Panics if a bound overflows the index type.
+#[inline]
+pub fn to_range<I: Step, R: RangeBounds<I>>(range: &R, len: I) -> Range<I> {
+    match try_to_range(range, len) {
+        Ok(index) => index,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/// Like `to_range`, but returns an error if a bound overflows the index type.
+#[inline]
+pub fn try_to_range<I: Step, R: RangeBounds<I>>(range: &R, len: I) -> Result<Range<I>, OutOfBounds<I>> {
+    match resolve_range(range, len) {
+        (index, false) => Ok(index),
+        (index, true) => Err(OutOfBounds { range: index, len }),
+    }
+}
+
+/// Like `to_range`, but bounds which overflow the index type saturate,
+/// which is enough for clamping the range to `0..len` afterwards.
+#[inline]
+pub fn to_range_saturating<I: Step, R: RangeBounds<I>>(range: &R, len: I) -> Range<I> {
+    resolve_range(range, len).0
+}
+
+/// Like `to_range`, but negative bounds count back from `len`,
+/// so `-3..` covers the last three elements.
+/// Panics if a bound overflows, or still lies before the start once resolved.
+#[inline]
+pub fn to_range_from_end<I: Idx, R: RangeBounds<I>>(range: &R, len: I) -> Range<I> {
+    let resolve = |bound: Bound<&I>| match bound {
+        Bound::Included(&i) if i < Zero::zero() => Bound::Included(len + i),
+        Bound::Excluded(&i) if i < Zero::zero() => Bound::Excluded(len + i),
+        Bound::Included(&i) => Bound::Included(i),
+        Bound::Excluded(&i) => Bound::Excluded(i),
+        Bound::Unbounded => Bound::Unbounded,
+    };
+    let bounds = (resolve(range.start_bound()), resolve(range.end_bound()));
+    let (index, overflowed) = resolve_range(&bounds, len);
+    if overflowed || index.start < Zero::zero() {
+        panic!("{}", OutOfBounds { range: index, len });
+    }
+    index
+}
+
+/// Shrinks `range` so that it lies within `0..len`.
+#[inline]
+pub fn clamp_range<I: Step>(range: Range<I>, len: I) -> Range<I> {
+    let end = cmp::min(range.end, len);
+    cmp::min(range.start, end)..end
+}
+
+/// Aborts the process if dropped while unwinding, by panicking again.
+/// Guards code which would leave a value in two places if it unwound.
+pub struct AbortOnUnwind(pub &'static str);
+
+impl Drop for AbortOnUnwind {
+    fn drop(&mut self) {
+        panic!("{}", self.0);
+    }
+}
+
+/// Borrows the element at `index` of the container behind `list` for `'b`,
+/// while references to other elements of it may still be alive.
+///
+/// The `&mut K` only lives for the `index_mut` call, which by the
+/// `UniqueIndexMut` contract neither covers nor invalidates other elements.
+///
+/// # Safety
+///
+/// `list` must be valid for `'b`, and nothing else may be borrowing the
+/// element at `index` during `'b`.
+#[inline]
+pub unsafe fn index_unique<'b, K, I>(list: *mut K, index: I) -> &'b mut K::Output
+    where K: ?Sized + UniqueIndexMut<I>
+{
+    let list = &mut *list;
+    let item: *mut K::Output = &mut list[index];
+    &mut *item
+}
+
+/// Converts an index (or length) into a `usize`.
+#[inline]
+pub fn to_usize<I: Idx>(index: I) -> usize {
+    index.to_usize().expect("index cannot be represented as a usize")
+}
+
+/// Converts a `usize` back into the index type.
+#[inline]
+pub fn from_usize<I: Idx>(n: usize) -> I {
+    <I as NumCast>::from(n).expect("usize cannot be represented in the index type")
+}
+
+/// Returns the longest contiguous run of `list[start..start + len]`
+/// which begins at `start`.
+#[cfg(any(feature = "std", feature = "bytes"))]
+pub fn deque_segment<T>(list: &VecDeque<T>, start: usize, len: usize) -> &[T] {
+    let (front, back) = list.as_slices();
+    if start < front.len() {
+        &front[start..cmp::min(front.len(), start + len)]
+    } else {
+        let start = start - front.len();
+        &back[start..start + len]
+    }
+}
+
+/// The mutable version of `deque_segment`.
+#[cfg(feature = "bytes")]
+pub fn deque_segment_mut<T>(list: &mut VecDeque<T>, start: usize, len: usize) -> &mut [T] {
+    let (front, back) = list.as_mut_slices();
+    if start < front.len() {
+        let end = cmp::min(front.len(), start + len);
+        &mut front[start..end]
+    } else {
+        let start = start - front.len();
+        &mut back[start..start + len]
     }
 }
@@ -0,0 +1,335 @@
+use core::fmt::{self, Debug};
+use core::marker;
+use core::ops::{Index, IndexMut, Range};
+use num_traits::Zero;
+use util::{to_usize, from_usize};
+use {Idx, Iter, IterMut, TakeSlice, UniqueIndexMut};
+
+/// An immutable circular view of a container, created with
+/// `TakeSlice::wrapping_slice`.
+///
+/// The range it was created with may run past the end of the container,
+/// in which case it wraps around to the start, like a ring buffer.
+///
+/// ```
+/// use owned_slice::TakeSlice;
+///
+/// let ring = [0, 1, 2, 3, 4, 5];
+/// let (tail, n) = (4, 4);
+/// let window = ring.wrapping_slice(tail..tail + n);
+/// assert_eq!(format!("{:?}", window), "[4, 5, 0, 1]");
+/// assert_eq!(ring.wrapping_slice(9..11)[1], 4);
+/// ```
+pub struct WrappingSlice<'a, K: 'a + ?Sized + Index<I, Output = T>, I: 'a + Idx, T: 'a> {
+    list: &'a K,
+    start: I,
+    len: I,
+    capacity: I,
+    ty: marker::PhantomData<T>,
+}
+
+/// A mutable circular view of a container, created with
+/// `TakeSlice::wrapping_slice_mut`.
+pub struct WrappingSliceMut<'a, K: 'a + ?Sized + IndexMut<I, Output = T>, I: 'a + Idx, T: 'a> {
+    list: &'a mut K,
+    start: I,
+    len: I,
+    capacity: I,
+    ty: marker::PhantomData<T>,
+}
+
+/// Reduces `range` modulo `capacity`, returning its wrapped start and its length.
+fn wrap_range<I: Idx>(range: Range<I>, capacity: I) -> (I, I) {
+    if range.start > range.end || range.end - range.start > capacity {
+        panic!("wrapping range {:?} is longer than the container's length {:?}", range, capacity);
+    }
+    let len = range.end - range.start;
+    if capacity == Zero::zero() {
+        return (Zero::zero(), len);
+    }
+    (from_usize(to_usize(range.start) % to_usize(capacity)), len)
+}
+
+/// Maps an index into the view to an index into the container.
+#[inline]
+fn wrap_index<I: Idx>(start: I, index: I, capacity: I) -> I {
+    // `start < capacity` and `index < len <= capacity`, so a single
+    // subtraction is enough
+    let distance_to_end = capacity - start;
+    if index < distance_to_end {
+        start + index
+    } else {
+        index - distance_to_end
+    }
+}
+
+impl<'a, K, I, T> WrappingSlice<'a, K, I, T>
+    where K: ?Sized + TakeSlice<T, I>,
+          I: Idx
+{
+    /// Creates a circular view of `index`, which may extend past the end of the container.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is longer than the container.
+    pub fn new(list: &'a K, index: Range<I>) -> WrappingSlice<'a, K, I, T> {
+        let capacity = list.len();
+        let (start, len) = wrap_range(index, capacity);
+        WrappingSlice {
+            list,
+            start,
+            len,
+            capacity,
+            ty: marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, K, I, T> WrappingSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    /// Returns the number of elements in the view.
+    #[inline]
+    pub fn len(&self) -> I {
+        self.len
+    }
+
+    /// Returns `true` if the view has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == Zero::zero()
+    }
+
+    /// Returns a reference to the element at `index`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: I) -> Option<&'a T> {
+        if index < self.len {
+            Some(&self.list[wrap_index(self.start, index, self.capacity)])
+        } else {
+            None
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, Self, I, T> {
+        self.index_range_full(..).iter()
+    }
+}
+
+impl<'a, K, I, T> WrappingSliceMut<'a, K, I, T>
+    where K: ?Sized + TakeSlice<T, I> + IndexMut<I>,
+          I: Idx
+{
+    /// Creates a mutable circular view of `index`,
+    /// which may extend past the end of the container.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is longer than the container.
+    pub fn new(list: &'a mut K, index: Range<I>) -> WrappingSliceMut<'a, K, I, T> {
+        let capacity = list.len();
+        let (start, len) = wrap_range(index, capacity);
+        WrappingSliceMut {
+            list,
+            start,
+            len,
+            capacity,
+            ty: marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, K, I, T> WrappingSliceMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx
+{
+    /// Returns the number of elements in the view.
+    #[inline]
+    pub fn len(&self) -> I {
+        self.len
+    }
+
+    /// Returns `true` if the view has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == Zero::zero()
+    }
+
+    /// Returns a reference to the element at `index`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: I) -> Option<&T> {
+        if index < self.len {
+            Some(&self.list[wrap_index(self.start, index, self.capacity)])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element at `index`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: I) -> Option<&mut T> {
+        if index < self.len {
+            Some(&mut self.list[wrap_index(self.start, index, self.capacity)])
+        } else {
+            None
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, Self, I, T> {
+        self.index_range_full(..).iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, Self, I, T>
+        where K: UniqueIndexMut<I>
+    {
+        self.index_range_full_mut(..).iter_mut()
+    }
+}
+
+impl<'a, K, I, T> Clone for WrappingSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, K, I, T> Copy for WrappingSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+}
+
+impl<'a, K, I, T> Index<I> for WrappingSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: I) -> &T {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, self.len),
+        }
+    }
+}
+
+impl<'a, K, I, T> Index<I> for WrappingSliceMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: I) -> &T {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, self.len),
+        }
+    }
+}
+
+impl<'a, K, I, T> IndexMut<I> for WrappingSliceMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx
+{
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut T {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(item) => item,
+            None => panic!("Index out of bounds: {:?} >= {:?}", index, len),
+        }
+    }
+}
+
+impl<'a, K, I, T> TakeSlice<T, I> for WrappingSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx
+{
+    fn len(&self) -> I {
+        self.len
+    }
+}
+
+impl<'a, K, I, T> TakeSlice<T, I> for WrappingSliceMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx
+{
+    fn len(&self) -> I {
+        self.len
+    }
+}
+
+// the view is never longer than the container, so distinct indices of the
+// view never wrap around onto the same element
+unsafe impl<'a, K, I, T> UniqueIndexMut<I> for WrappingSliceMut<'a, K, I, T>
+    where K: ?Sized + UniqueIndexMut<I, Output = T>,
+          I: Idx
+{
+}
+
+impl<'a, K, I, T> Debug for WrappingSlice<'a, K, I, T>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          T: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K, I, T> Debug for WrappingSliceMut<'a, K, I, T>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          T: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use TakeSlice;
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..10).collect()
+    }
+
+    #[test]
+    fn wrapping() {
+        let v = test_vec();
+        let w = v.wrapping_slice(7..13);
+        assert_eq!(format!("{:?}", w), "[7, 8, 9, 0, 1, 2]");
+        assert_eq!(w.index_range(2..4), [9, 0]);
+        assert_eq!(w.get(6), None);
+        assert_eq!(v.wrapping_slice(23..25)[0], 3);
+        assert!(v.wrapping_slice(4..4).is_empty());
+    }
+
+    #[test]
+    fn wrapping_mut() {
+        let mut v = test_vec();
+        {
+            let mut w = v.wrapping_slice_mut(8..12);
+            w.index_range_full_mut(..).reverse();
+            for x in w.iter_mut() {
+                *x *= 10;
+            }
+        }
+        assert_eq!(v, [90, 80, 2, 3, 4, 5, 6, 7, 10, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn longer_than_container() {
+        test_vec().wrapping_slice(5..16);
+    }
+}
@@ -0,0 +1,295 @@
+use core::fmt::{self, Debug};
+use core::iter;
+use core::ops::{Index, IndexMut};
+use num_traits::Zero;
+use {BoundsPolicy, Idx, Iter, IterMut, Slice, SliceMut, UniqueIndexMut};
+
+/// An immutable view pairing up the elements of two slices of the same
+/// length, created with `Slice::zip`.
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use owned_slice::TakeSlice;
+///
+/// let times: VecDeque<u32> = vec![10, 20, 30].into_iter().collect();
+/// let values = vec![1.5, 2.5, 3.5];
+/// let pairs = times.index_range_full(..).zip(values.index_range_full(..));
+/// assert_eq!(pairs.get(1), Some((&20, &2.5)));
+/// assert_eq!(pairs.iter().filter(|&(&t, _)| t > 15).count(), 2);
+/// ```
+pub struct Zip<'a, K, L, I, A, B>
+    where K: 'a + ?Sized + Index<I, Output = A>,
+          L: 'a + ?Sized + Index<I, Output = B>,
+          I: 'a + Idx,
+          A: 'a,
+          B: 'a
+{
+    left: Slice<'a, K, I, A>,
+    right: Slice<'a, L, I, B>,
+}
+
+/// A mutable view pairing up the elements of two slices of the same
+/// length, created with `SliceMut::zip`.
+pub struct ZipMut<'a, K, L, I, A, B>
+    where K: 'a + ?Sized + IndexMut<I, Output = A>,
+          L: 'a + ?Sized + IndexMut<I, Output = B>,
+          I: 'a + Idx,
+          A: 'a,
+          B: 'a
+{
+    left: SliceMut<'a, K, I, A>,
+    right: SliceMut<'a, L, I, B>,
+}
+
+#[inline]
+fn assert_same_len<I: Idx>(left: I, right: I) {
+    if left != right {
+        panic!("zipped slices have different lengths: {:?} != {:?}", left, right);
+    }
+}
+
+impl<'a, K, I, T, P> Slice<'a, K, I, T, P>
+    where K: ?Sized + Index<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Pairs up each element of `self` with the element of `other`
+    /// at the same index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices have different lengths.
+    pub fn zip<L, U>(self, other: Slice<'a, L, I, U>) -> Zip<'a, K, L, I, T, U>
+        where L: ?Sized + Index<I, Output = U>
+    {
+        assert_same_len(self.len(), other.len());
+        Zip {
+            left: self.with_policy(),
+            right: other,
+        }
+    }
+}
+
+impl<'a, K, I, T, P> SliceMut<'a, K, I, T, P>
+    where K: ?Sized + IndexMut<I, Output = T>,
+          I: Idx,
+          P: BoundsPolicy
+{
+    /// Pairs up each element of `self` with the element of `other`
+    /// at the same index, allowing both to be modified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices have different lengths.
+    pub fn zip<L, U>(self, other: SliceMut<'a, L, I, U>) -> ZipMut<'a, K, L, I, T, U>
+        where L: ?Sized + IndexMut<I, Output = U>
+    {
+        assert_same_len(self.len(), other.len());
+        ZipMut {
+            left: self.with_policy(),
+            right: other,
+        }
+    }
+}
+
+impl<'a, K, L, I, A, B> Zip<'a, K, L, I, A, B>
+    where K: ?Sized + Index<I, Output = A>,
+          L: ?Sized + Index<I, Output = B>,
+          I: Idx
+{
+    /// Returns the number of pairs in the view.
+    #[inline]
+    pub fn len(&self) -> I {
+        self.left.len()
+    }
+
+    /// Returns `true` if the view has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.left.len() == Zero::zero()
+    }
+
+    /// Returns the pair of elements at `index`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: I) -> Option<(&'a A, &'a B)> {
+        match (self.left.get(index), self.right.get(index)) {
+            (Some(a), Some(b)) => Some((a, b)),
+            _ => None,
+        }
+    }
+
+    pub fn iter(&self) -> iter::Zip<Iter<'a, K, I, A>, Iter<'a, L, I, B>> {
+        self.left.iter().zip(self.right.iter())
+    }
+
+    /// Splits the view back into its two slices.
+    pub fn into_parts(self) -> (Slice<'a, K, I, A>, Slice<'a, L, I, B>) {
+        (self.left, self.right)
+    }
+}
+
+impl<'a, K, L, I, A, B> ZipMut<'a, K, L, I, A, B>
+    where K: ?Sized + IndexMut<I, Output = A>,
+          L: ?Sized + IndexMut<I, Output = B>,
+          I: Idx
+{
+    /// Returns the number of pairs in the view.
+    #[inline]
+    pub fn len(&self) -> I {
+        self.left.len()
+    }
+
+    /// Returns `true` if the view has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.left.len() == Zero::zero()
+    }
+
+    /// Returns the pair of elements at `index`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: I) -> Option<(&A, &B)> {
+        match (self.left.get(index), self.right.get(index)) {
+            (Some(a), Some(b)) => Some((a, b)),
+            _ => None,
+        }
+    }
+
+    /// Returns mutable references to the pair of elements at `index`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: I) -> Option<(&mut A, &mut B)> {
+        match (self.left.get_mut(index), self.right.get_mut(index)) {
+            (Some(a), Some(b)) => Some((a, b)),
+            _ => None,
+        }
+    }
+
+    pub fn iter(&self) -> iter::Zip<Iter<'_, K, I, A>, Iter<'_, L, I, B>> {
+        self.left.iter().zip(self.right.iter())
+    }
+
+    pub fn iter_mut(self) -> iter::Zip<IterMut<'a, K, I, A>, IterMut<'a, L, I, B>>
+        where K: UniqueIndexMut<I>,
+              L: UniqueIndexMut<I>
+    {
+        self.left.iter_mut().zip(self.right.iter_mut())
+    }
+
+    /// Splits the view back into its two slices.
+    pub fn into_parts(self) -> (SliceMut<'a, K, I, A>, SliceMut<'a, L, I, B>) {
+        (self.left, self.right)
+    }
+}
+
+impl<'a, K, L, I, A, B> Clone for Zip<'a, K, L, I, A, B>
+    where K: ?Sized + Index<I, Output = A>,
+          L: ?Sized + Index<I, Output = B>,
+          I: Idx
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, K, L, I, A, B> Copy for Zip<'a, K, L, I, A, B>
+    where K: ?Sized + Index<I, Output = A>,
+          L: ?Sized + Index<I, Output = B>,
+          I: Idx
+{
+}
+
+impl<'a, K, L, I, A, B> IntoIterator for Zip<'a, K, L, I, A, B>
+    where K: ?Sized + Index<I, Output = A>,
+          L: ?Sized + Index<I, Output = B>,
+          I: Idx
+{
+    type Item = (&'a A, &'a B);
+    type IntoIter = iter::Zip<Iter<'a, K, I, A>, Iter<'a, L, I, B>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, L, I, A, B> IntoIterator for ZipMut<'a, K, L, I, A, B>
+    where K: ?Sized + UniqueIndexMut<I, Output = A>,
+          L: ?Sized + UniqueIndexMut<I, Output = B>,
+          I: Idx
+{
+    type Item = (&'a mut A, &'a mut B);
+    type IntoIter = iter::Zip<IterMut<'a, K, I, A>, IterMut<'a, L, I, B>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<'a, K, L, I, A, B> Debug for Zip<'a, K, L, I, A, B>
+    where K: ?Sized + Index<I, Output = A>,
+          L: ?Sized + Index<I, Output = B>,
+          I: Idx,
+          A: Debug,
+          B: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K, L, I, A, B> Debug for ZipMut<'a, K, L, I, A, B>
+    where K: ?Sized + IndexMut<I, Output = A>,
+          L: ?Sized + IndexMut<I, Output = B>,
+          I: Idx,
+          A: Debug,
+          B: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use TakeSlice;
+
+    fn test_vec() -> VecDeque<usize> {
+        (0..10).collect()
+    }
+
+    #[test]
+    fn zip() {
+        let v = test_vec();
+        let names = ["a", "b", "c"];
+        let z = v.index_range(4..7).zip(names.index_range_full(..));
+        assert_eq!(format!("{:?}", z), r#"[(4, "a"), (5, "b"), (6, "c")]"#);
+        assert_eq!(z.get(2), Some((&6, &"c")));
+        assert_eq!(z.get(3), None);
+        assert_eq!(z.into_iter().map(|(&x, _)| x).sum::<usize>(), 15);
+    }
+
+    #[test]
+    fn zip_mut() {
+        let mut v = test_vec();
+        let mut w: VecDeque<_> = vec![0; 3].into();
+        {
+            let mut z = v.index_range_to_mut(..3).zip(w.index_range_full_mut(..));
+            if let Some((a, b)) = z.get_mut(0) {
+                *a = 7;
+                *b = 1;
+            }
+            for (a, b) in z.iter_mut().skip(1) {
+                *b = *a * 2;
+            }
+        }
+        assert_eq!(v.index_range_to(..3), [7, 1, 2]);
+        assert_eq!(w, [1, 2, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zip_different_lengths() {
+        let v = test_vec();
+        v.index_range_to(..3).zip(v.index_range_to(..4));
+    }
+}